@@ -0,0 +1,34 @@
+use crate::pickups::components::{Pickup, PickupKind};
+use bevy::prelude::*;
+use serde::{Deserialize, Serialize};
+
+/// Player ability that has to be unlocked before it can be used.
+#[derive(
+    Reflect,
+    Serialize,
+    Deserialize,
+    Default,
+    Debug,
+    Clone,
+    Copy,
+    PartialEq,
+    Eq,
+    Hash,
+    PartialOrd,
+    Ord,
+)]
+pub enum Ability {
+    #[default]
+    Dash,
+    /// One extra air jump.
+    DoubleJump,
+}
+
+/// Pickup placed in Tiled that permanently unlocks an ability for this save.
+/// Removed on load once its ability is unlocked.
+#[derive(Component, Reflect, Default, Debug, Clone)]
+#[reflect(Component, Default)]
+#[require(Pickup = Pickup { kind: PickupKind::Ability, value: 0, respawn_time: 0.0 })]
+pub struct AbilityUnlock {
+    pub ability: Ability,
+}
@@ -0,0 +1,6 @@
+pub mod components;
+mod plugin;
+pub mod resources;
+mod systems;
+
+pub use plugin::AbilitiesPlugin;
@@ -0,0 +1,25 @@
+use super::components::{Ability, AbilityUnlock};
+use super::resources::UnlockedAbilities;
+use super::systems::{apply_air_jump_unlocks, remove_unlocked_pickups, unlock_abilities};
+use crate::pickups::PickupSystemSet;
+use bevy::prelude::*;
+
+pub struct AbilitiesPlugin;
+
+impl Plugin for AbilitiesPlugin {
+    fn build(&self, app: &mut App) {
+        app.register_type::<Ability>()
+            .register_type::<AbilityUnlock>()
+            .register_type::<UnlockedAbilities>()
+            .init_resource::<UnlockedAbilities>()
+            .add_systems(
+                Update,
+                (
+                    remove_unlocked_pickups,
+                    (unlock_abilities, apply_air_jump_unlocks)
+                        .chain()
+                        .after(PickupSystemSet::Collect),
+                ),
+            );
+    }
+}
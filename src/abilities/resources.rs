@@ -0,0 +1,13 @@
+use super::components::Ability;
+use bevy::{platform::collections::HashSet, prelude::*};
+
+/// Abilities unlocked in this save.
+#[derive(Resource, Reflect, Default)]
+#[reflect(Resource)]
+pub struct UnlockedAbilities(pub HashSet<Ability>);
+
+impl UnlockedAbilities {
+    pub fn has(&self, ability: Ability) -> bool {
+        self.0.contains(&ability)
+    }
+}
@@ -0,0 +1,49 @@
+use super::components::{Ability, AbilityUnlock};
+use super::resources::UnlockedAbilities;
+use crate::pickups::components::PickupKind;
+use crate::pickups::messages::PickupCollected;
+use crate::player::{AirJumps, Player};
+use bevy::prelude::*;
+
+/// Unlocks the ability of each collected ability pickup.
+pub fn unlock_abilities(
+    mut messages: MessageReader<PickupCollected>,
+    unlocks: Query<&AbilityUnlock>,
+    mut unlocked: ResMut<UnlockedAbilities>,
+) {
+    for evt in messages.read() {
+        if evt.kind != PickupKind::Ability {
+            continue;
+        }
+        if let Ok(unlock) = unlocks.get(evt.entity) {
+            info!("Unlocked ability {:?}", unlock.ability);
+            unlocked.0.insert(unlock.ability);
+        }
+    }
+}
+
+/// Removes pickups for abilities this save already has.
+pub fn remove_unlocked_pickups(
+    mut commands: Commands,
+    unlocks: Query<(Entity, &AbilityUnlock), Added<AbilityUnlock>>,
+    unlocked: Res<UnlockedAbilities>,
+) {
+    for (entity, unlock) in &unlocks {
+        if unlocked.has(unlock.ability) {
+            commands.entity(entity).despawn();
+        }
+    }
+}
+
+/// Grants the player the air jumps of unlocked abilities.
+pub fn apply_air_jump_unlocks(
+    unlocked: Res<UnlockedAbilities>,
+    mut players: Query<&mut AirJumps, With<Player>>,
+) {
+    let extra = u32::from(unlocked.has(Ability::DoubleJump));
+    for mut air_jumps in &mut players {
+        if air_jumps.unlocked != extra {
+            air_jumps.unlocked = extra;
+        }
+    }
+}
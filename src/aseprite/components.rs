@@ -0,0 +1,28 @@
+use super::sheet::AsepriteSheet;
+use bevy::prelude::*;
+
+/// Plays a named tag of an Aseprite sheet, by building the entity's `SpriteAnimation` from it.
+/// Changing `tag` switches animations. The animation is rebuilt when the sheet is (re)loaded.
+#[derive(Component, Debug, Clone)]
+pub struct AsepriteAnimation {
+    pub sheet: Handle<AsepriteSheet>,
+    pub tag: &'static str,
+    /// Named frame events, by frame offset within the tag
+    pub events: Vec<(usize, &'static str)>,
+}
+
+impl AsepriteAnimation {
+    pub fn new(sheet: Handle<AsepriteSheet>, tag: &'static str) -> Self {
+        Self {
+            sheet,
+            tag,
+            events: Vec::new(),
+        }
+    }
+
+    /// Writes a `SpriteFrameEvent` named `name` whenever the tag's `offset`th frame is shown.
+    pub fn with_event(mut self, offset: usize, name: &'static str) -> Self {
+        self.events.push((offset, name));
+        self
+    }
+}
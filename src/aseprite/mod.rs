@@ -0,0 +1,7 @@
+pub mod components;
+mod plugin;
+mod sheet;
+mod systems;
+
+pub use plugin::AsepritePlugin;
+pub use sheet::AsepriteSheet;
@@ -0,0 +1,13 @@
+use super::sheet::{AsepriteSheet, AsepriteSheetLoader};
+use super::systems::apply_aseprite_animations;
+use bevy::prelude::*;
+
+pub struct AsepritePlugin;
+
+impl Plugin for AsepritePlugin {
+    fn build(&self, app: &mut App) {
+        app.init_asset::<AsepriteSheet>()
+            .init_asset_loader::<AsepriteSheetLoader>()
+            .add_systems(PostUpdate, apply_aseprite_animations);
+    }
+}
@@ -0,0 +1,191 @@
+use crate::core::components::{AnimationMode, SpriteAnimation};
+use bevy::{
+    asset::{AssetLoader, LoadContext, io::Reader},
+    platform::collections::HashMap,
+    prelude::*,
+};
+use serde::Deserialize;
+
+/// Sprite sheet metadata exported from Aseprite as JSON (File > Export Sprite Sheet,
+/// with "Array" frames and tags enabled), loaded from `.aseprite.json` files.
+///
+/// The atlas layout is the `layout` labeled asset, e.g. `sprites/knight.aseprite.json#layout`,
+/// so sprites can use it before the sheet has loaded.
+///
+/// The sheets in `assets/sprites` have no `.aseprite` sources; they are written by hand in
+/// this format to match the existing PNGs, so edit them directly when the art changes.
+/// They keep only the fields read here: each frame's `frame` rect and `duration`, and the
+/// meta `image`, `size` and `frameTags`.
+#[derive(Asset, TypePath, Debug)]
+pub struct AsepriteSheet {
+    #[dependency]
+    pub image: Handle<Image>,
+    /// Seconds each frame is shown, by atlas index
+    pub durations: Vec<f32>,
+    pub tags: HashMap<String, AsepriteTag>,
+}
+
+impl AsepriteSheet {
+    /// Builds the animation for a tag, with its direction and frame durations.
+    /// Tags repeating once hold their last frame; other repeat counts loop.
+    pub fn animation(&self, tag: &str) -> Option<SpriteAnimation> {
+        let tag = self.tags.get(tag)?;
+        let durations = self.durations.get(tag.from..=tag.to)?.to_vec();
+
+        let ping_pong = matches!(
+            tag.direction,
+            AsepriteDirection::Pingpong | AsepriteDirection::PingpongReverse
+        );
+        let mode = if tag.repeat == Some(1) {
+            AnimationMode::Once
+        } else if ping_pong {
+            AnimationMode::PingPong
+        } else {
+            AnimationMode::Loop
+        };
+
+        let animation = SpriteAnimation::new(tag.from, tag.to, 10)
+            .with_mode(mode)
+            .with_frame_durations(durations);
+        let reversed = matches!(
+            tag.direction,
+            AsepriteDirection::Reverse | AsepriteDirection::PingpongReverse
+        );
+        Some(if reversed {
+            animation.reversed()
+        } else {
+            animation
+        })
+    }
+}
+
+/// A named frame range of an Aseprite sheet.
+#[derive(Debug, Clone)]
+pub struct AsepriteTag {
+    /// First atlas index
+    pub from: usize,
+    /// Last atlas index
+    pub to: usize,
+    pub direction: AsepriteDirection,
+    /// Times the tag plays before stopping. None repeats forever.
+    pub repeat: Option<u32>,
+}
+
+#[derive(Deserialize, Debug, Clone, Copy, PartialEq, Eq)]
+#[serde(rename_all = "snake_case")]
+pub enum AsepriteDirection {
+    Forward,
+    Reverse,
+    Pingpong,
+    PingpongReverse,
+}
+
+#[derive(Deserialize)]
+struct SheetJson {
+    frames: Vec<FrameJson>,
+    meta: MetaJson,
+}
+
+#[derive(Deserialize)]
+struct FrameJson {
+    frame: RectJson,
+    /// Milliseconds
+    duration: u32,
+}
+
+#[derive(Deserialize)]
+struct RectJson {
+    x: u32,
+    y: u32,
+    w: u32,
+    h: u32,
+}
+
+#[derive(Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct MetaJson {
+    /// Image path relative to the JSON file
+    image: String,
+    size: SizeJson,
+    #[serde(default)]
+    frame_tags: Vec<TagJson>,
+}
+
+#[derive(Deserialize)]
+struct SizeJson {
+    w: u32,
+    h: u32,
+}
+
+#[derive(Deserialize)]
+struct TagJson {
+    name: String,
+    from: usize,
+    to: usize,
+    direction: AsepriteDirection,
+    /// Aseprite writes the repeat count as a string, and omits it for infinite
+    repeat: Option<String>,
+}
+
+/// Loads `.aseprite.json` files as `AsepriteSheet`, along with the image they describe.
+#[derive(Default, TypePath)]
+pub struct AsepriteSheetLoader;
+
+impl AssetLoader for AsepriteSheetLoader {
+    type Asset = AsepriteSheet;
+    type Settings = ();
+    type Error = BevyError;
+
+    async fn load(
+        &self,
+        reader: &mut dyn Reader,
+        _settings: &(),
+        load_context: &mut LoadContext<'_>,
+    ) -> Result<Self::Asset, Self::Error> {
+        let mut bytes = Vec::new();
+        reader.read_to_end(&mut bytes).await?;
+        let json: SheetJson = serde_json::from_slice(&bytes)?;
+
+        let mut layout =
+            TextureAtlasLayout::new_empty(UVec2::new(json.meta.size.w, json.meta.size.h));
+        for frame in &json.frames {
+            let rect = &frame.frame;
+            layout.add_texture(URect::new(rect.x, rect.y, rect.x + rect.w, rect.y + rect.h));
+        }
+
+        let image_path = load_context.path().resolve_embed(&json.meta.image)?;
+        let image = load_context.load(image_path);
+        load_context.add_labeled_asset("layout".to_string(), layout);
+
+        let durations = json
+            .frames
+            .iter()
+            .map(|frame| frame.duration as f32 / 1000.0)
+            .collect();
+        let tags = json
+            .meta
+            .frame_tags
+            .into_iter()
+            .map(|tag| {
+                let repeat = tag.repeat.and_then(|r| r.parse().ok()).filter(|r| *r > 0);
+                let tag_data = AsepriteTag {
+                    from: tag.from,
+                    to: tag.to,
+                    direction: tag.direction,
+                    repeat,
+                };
+                (tag.name, tag_data)
+            })
+            .collect();
+
+        Ok(AsepriteSheet {
+            image,
+            durations,
+            tags,
+        })
+    }
+
+    fn extensions(&self) -> &[&str] {
+        &["aseprite.json"]
+    }
+}
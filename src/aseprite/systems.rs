@@ -0,0 +1,44 @@
+use super::components::AsepriteAnimation;
+use super::sheet::AsepriteSheet;
+use bevy::prelude::*;
+
+/// Builds the `SpriteAnimation` of entities whose `AsepriteAnimation` changed, or whose
+/// sheet has just loaded or been modified, and starts it from its first frame.
+pub fn apply_aseprite_animations(
+    mut commands: Commands,
+    sheets: Res<Assets<AsepriteSheet>>,
+    mut sheet_events: MessageReader<AssetEvent<AsepriteSheet>>,
+    mut query: Query<(Entity, Ref<AsepriteAnimation>, &mut Sprite)>,
+) {
+    let reloaded: Vec<AssetId<AsepriteSheet>> = sheet_events
+        .read()
+        .filter_map(|event| match event {
+            AssetEvent::LoadedWithDependencies { id } | AssetEvent::Modified { id } => Some(*id),
+            _ => None,
+        })
+        .collect();
+
+    for (entity, animation, mut sprite) in &mut query {
+        if !animation.is_changed() && !reloaded.contains(&animation.sheet.id()) {
+            continue;
+        }
+        // Not loaded yet; the load event brings us back here
+        let Some(sheet) = sheets.get(&animation.sheet) else {
+            continue;
+        };
+        let Some(mut sprite_animation) = sheet.animation(animation.tag) else {
+            warn!("Aseprite sheet has no tag named {:?}", animation.tag);
+            continue;
+        };
+
+        for &(offset, name) in &animation.events {
+            let frame = sprite_animation.first + offset;
+            sprite_animation = sprite_animation.with_event(frame, name);
+        }
+
+        if let Some(atlas) = &mut sprite.texture_atlas {
+            atlas.index = sprite_animation.start_frame();
+        }
+        commands.entity(entity).insert(sprite_animation);
+    }
+}
@@ -1,5 +1,8 @@
 use super::components::{Music, Sfx};
-use super::systems::{load_sfx, play_coin_sound, spawn_music};
+use super::systems::{
+    load_sfx, play_death_sound, play_footstep_sound, play_jump_sound, play_pickup_sound,
+    play_switch_sound, spawn_music,
+};
 use bevy::prelude::*;
 
 pub struct AudioPlugin;
@@ -9,6 +12,15 @@ impl Plugin for AudioPlugin {
         app.register_type::<Music>()
             .register_type::<Sfx>()
             .add_systems(Startup, (load_sfx, spawn_music))
-            .add_systems(Update, play_coin_sound);
+            .add_systems(
+                Update,
+                (
+                    play_pickup_sound,
+                    play_switch_sound,
+                    play_jump_sound,
+                    play_footstep_sound,
+                ),
+            )
+            .add_observer(play_death_sound);
     }
 }
@@ -4,4 +4,8 @@ use bevy::prelude::*;
 #[derive(Resource)]
 pub struct SfxHandles {
     pub coin: Handle<AudioSource>,
+    pub power_up: Handle<AudioSource>,
+    pub tap: Handle<AudioSource>,
+    pub jump: Handle<AudioSource>,
+    pub hurt: Handle<AudioSource>,
 }
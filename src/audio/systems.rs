@@ -1,6 +1,10 @@
 use super::components::{Music, Sfx};
 use super::resources::SfxHandles;
-use crate::pickups::messages::CoinCollected;
+use crate::core::messages::SpriteFrameEvent;
+use crate::pickups::components::PickupKind;
+use crate::pickups::messages::PickupCollected;
+use crate::player::{EnterPlayerState, FOOTSTEP, Player, PlayerState, PlayerStateChanged};
+use crate::puzzle::messages::SwitchToggled;
 use bevy::audio::Volume;
 use bevy::prelude::*;
 
@@ -8,6 +12,10 @@ use bevy::prelude::*;
 pub fn load_sfx(mut commands: Commands, asset_server: Res<AssetServer>) {
     commands.insert_resource(SfxHandles {
         coin: asset_server.load("sounds/coin.wav"),
+        power_up: asset_server.load("sounds/power_up.wav"),
+        tap: asset_server.load("sounds/tap.wav"),
+        jump: asset_server.load("sounds/jump.wav"),
+        hurt: asset_server.load("sounds/hurt.wav"),
     });
 }
 
@@ -22,13 +30,66 @@ pub fn spawn_music(mut commands: Commands, asset_server: Res<AssetServer>) {
     ));
 }
 
-/// Plays coin pickup sound when CoinCollected is received.
-pub fn play_coin_sound(
-    mut messages: MessageReader<CoinCollected>,
+/// Plays the pickup sound matching the kind of each PickupCollected.
+pub fn play_pickup_sound(
+    mut messages: MessageReader<PickupCollected>,
+    mut commands: Commands,
+    sfx: Res<SfxHandles>,
+) {
+    for evt in messages.read() {
+        let sound = match evt.kind {
+            PickupKind::Coin | PickupKind::Key => sfx.coin.clone(),
+            PickupKind::PowerUp | PickupKind::Ability => sfx.power_up.clone(),
+        };
+        commands.spawn((Sfx, AudioPlayer::new(sound)));
+    }
+}
+
+/// Plays a click when a switch is toggled.
+pub fn play_switch_sound(
+    mut messages: MessageReader<SwitchToggled>,
     mut commands: Commands,
     sfx: Res<SfxHandles>,
 ) {
     for _ in messages.read() {
-        commands.spawn((Sfx, AudioPlayer::new(sfx.coin.clone())));
+        commands.spawn((Sfx, AudioPlayer::new(sfx.tap.clone())));
+    }
+}
+
+/// Plays the jump sound when the player jumps or air-jumps.
+pub fn play_jump_sound(
+    mut messages: MessageReader<PlayerStateChanged>,
+    mut commands: Commands,
+    sfx: Res<SfxHandles>,
+) {
+    for evt in messages.read() {
+        if matches!(evt.to, PlayerState::Jump | PlayerState::Flip) {
+            commands.spawn((Sfx, AudioPlayer::new(sfx.jump.clone())));
+        }
+    }
+}
+
+/// Plays the hurt sound as the player enters the Dead state.
+pub fn play_death_sound(enter: On<EnterPlayerState>, mut commands: Commands, sfx: Res<SfxHandles>) {
+    if enter.state == PlayerState::Dead {
+        commands.spawn((Sfx, AudioPlayer::new(sfx.hurt.clone())));
+    }
+}
+
+/// Plays a quiet tap on the player's footstep animation frames.
+pub fn play_footstep_sound(
+    mut messages: MessageReader<SpriteFrameEvent>,
+    players: Query<(), With<Player>>,
+    mut commands: Commands,
+    sfx: Res<SfxHandles>,
+) {
+    for evt in messages.read() {
+        if evt.name == FOOTSTEP && players.contains(evt.entity) {
+            commands.spawn((
+                Sfx,
+                AudioPlayer::new(sfx.tap.clone()),
+                PlaybackSettings::DESPAWN.with_volume(Volume::Linear(0.2)),
+            ));
+        }
     }
 }
@@ -2,4 +2,5 @@ mod components;
 mod plugin;
 mod systems;
 
+pub use components::FollowCamera;
 pub use plugin::CameraPlugin;
@@ -1,4 +1,5 @@
 use super::components::{CameraInitialized, FollowCamera};
+use crate::killzone::components::DeathTimer;
 use crate::player::Player;
 use bevy::prelude::*;
 
@@ -50,9 +51,10 @@ pub fn draw_camera_bounds(mut gizmos: Gizmos, camera: Query<&FollowCamera>) {
     );
 }
 
+/// Smoothly follows the player within the camera limits, holding still while they die.
 pub fn follow_player(
     time: Res<Time>,
-    player: Query<&Transform, (Without<Camera2d>, With<Player>)>,
+    player: Query<&Transform, (Without<Camera2d>, With<Player>, Without<DeathTimer>)>,
     mut camera: Query<
         (
             Entity,
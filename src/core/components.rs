@@ -9,24 +9,181 @@ pub struct Speed(pub f32);
 #[reflect(Resource)]
 pub struct Score(pub u32);
 
-/// Simple looping sprite animation.
+/// How a `SpriteAnimation` continues past the end of its frame range.
+#[derive(Reflect, Default, Debug, Clone, Copy, PartialEq, Eq)]
+pub enum AnimationMode {
+    /// Restart from the first frame
+    #[default]
+    Loop,
+    /// Hold the last frame and write `SpriteAnimationFinished`
+    Once,
+    /// Play back and forth between the first and last frames
+    PingPong,
+}
+
+/// Sprite animation over a contiguous range of atlas frames.
 #[derive(Component, Reflect)]
 #[reflect(Component)]
 pub struct SpriteAnimation {
     pub first: usize,
     pub last: usize,
+    pub mode: AnimationMode,
+    /// Seconds each frame is shown, starting at `first`. Frames past the end of the list
+    /// use its last entry.
+    pub frame_durations: Vec<f32>,
+    /// Named events written as `SpriteFrameEvent` when their atlas frame is shown
+    pub events: Vec<(usize, &'static str)>,
     pub timer: Timer,
+    /// Playing from `last` toward `first`. Flips at each end in ping-pong mode.
+    pub reversed: bool,
+    /// Reached the end in once mode
+    pub finished: bool,
 }
 
 impl SpriteAnimation {
+    /// Loops `first..=last` at a constant frame rate.
     pub fn new(first: usize, last: usize, fps: u8) -> Self {
+        let duration = 1.0 / fps as f32;
         Self {
             first,
             last,
-            timer: Timer::new(
-                Duration::from_secs_f32(1.0 / fps as f32),
-                TimerMode::Repeating,
-            ),
+            mode: AnimationMode::Loop,
+            frame_durations: vec![duration],
+            events: Vec::new(),
+            timer: Timer::new(Duration::from_secs_f32(duration), TimerMode::Once),
+            reversed: false,
+            finished: false,
+        }
+    }
+
+    pub fn with_mode(mut self, mode: AnimationMode) -> Self {
+        self.mode = mode;
+        self
+    }
+
+    /// Sets per-frame durations in seconds, starting at `first`.
+    pub fn with_frame_durations(mut self, durations: impl Into<Vec<f32>>) -> Self {
+        self.frame_durations = durations.into();
+        self.timer
+            .set_duration(Duration::from_secs_f32(self.frame_duration(self.first)));
+        self
+    }
+
+    /// Plays from `last` toward `first`.
+    pub fn reversed(mut self) -> Self {
+        self.reversed = true;
+        self.timer
+            .set_duration(Duration::from_secs_f32(self.frame_duration(self.last)));
+        self
+    }
+
+    /// Atlas index the animation starts on.
+    pub fn start_frame(&self) -> usize {
+        if self.reversed { self.last } else { self.first }
+    }
+
+    /// Writes a `SpriteFrameEvent` named `name` whenever atlas frame `frame` is shown.
+    pub fn with_event(mut self, frame: usize, name: &'static str) -> Self {
+        self.events.push((frame, name));
+        self
+    }
+
+    /// Seconds atlas frame `index` is shown.
+    pub fn frame_duration(&self, index: usize) -> f32 {
+        let offset = index.saturating_sub(self.first);
+        self.frame_durations
+            .get(offset)
+            .or(self.frame_durations.last())
+            .copied()
+            .unwrap_or(0.1)
+    }
+
+    /// The frame after `index`, or None once a once-mode animation has ended.
+    pub fn next_frame(&mut self, index: usize) -> Option<usize> {
+        if self.first == self.last {
+            return (self.mode != AnimationMode::Once).then_some(self.first);
         }
+
+        let at_end = if self.reversed {
+            index <= self.first
+        } else {
+            index >= self.last
+        };
+        match self.mode {
+            AnimationMode::Loop if at_end => return Some(self.start_frame()),
+            AnimationMode::Once if at_end => return None,
+            AnimationMode::PingPong if at_end => self.reversed = !self.reversed,
+            _ => {}
+        }
+        Some(if self.reversed { index - 1 } else { index + 1 })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Frames `animation` shows after `start`, up to `count` of them.
+    fn play(animation: &mut SpriteAnimation, start: usize, count: usize) -> Vec<usize> {
+        std::iter::successors(Some(start), |&index| animation.next_frame(index))
+            .skip(1)
+            .take(count)
+            .collect()
+    }
+
+    #[test]
+    fn loop_restarts_from_first_frame() {
+        let mut animation = SpriteAnimation::new(2, 4, 10);
+
+        assert_eq!(play(&mut animation, 2, 5), [3, 4, 2, 3, 4]);
+    }
+
+    #[test]
+    fn once_ends_on_last_frame() {
+        let mut animation = SpriteAnimation::new(2, 4, 10).with_mode(AnimationMode::Once);
+
+        assert_eq!(play(&mut animation, 2, 5), [3, 4]);
+    }
+
+    #[test]
+    fn reversed_loop_restarts_from_last_frame() {
+        let mut animation = SpriteAnimation::new(2, 4, 10).reversed();
+
+        assert_eq!(animation.start_frame(), 4);
+        assert_eq!(play(&mut animation, 4, 5), [3, 2, 4, 3, 2]);
+    }
+
+    #[test]
+    fn reversed_once_ends_on_first_frame() {
+        let mut animation = SpriteAnimation::new(2, 4, 10)
+            .with_mode(AnimationMode::Once)
+            .reversed();
+
+        assert_eq!(play(&mut animation, 4, 5), [3, 2]);
+    }
+
+    #[test]
+    fn ping_pong_turns_around_at_both_ends() {
+        let mut animation = SpriteAnimation::new(2, 4, 10).with_mode(AnimationMode::PingPong);
+
+        assert_eq!(play(&mut animation, 2, 6), [3, 4, 3, 2, 3, 4]);
+    }
+
+    #[test]
+    fn reversed_ping_pong_starts_toward_first_frame() {
+        let mut animation = SpriteAnimation::new(2, 4, 10)
+            .with_mode(AnimationMode::PingPong)
+            .reversed();
+
+        assert_eq!(play(&mut animation, 4, 6), [3, 2, 3, 4, 3, 2]);
+    }
+
+    #[test]
+    fn single_frame_holds_unless_once() {
+        let mut looping = SpriteAnimation::new(5, 5, 10);
+        assert_eq!(looping.next_frame(5), Some(5));
+
+        let mut once = SpriteAnimation::new(5, 5, 10).with_mode(AnimationMode::Once);
+        assert_eq!(once.next_frame(5), None);
     }
 }
@@ -0,0 +1,15 @@
+use bevy::prelude::*;
+
+/// Written when a once-mode `SpriteAnimation` reaches its last frame.
+#[derive(Message, Debug, Clone, Copy)]
+pub struct SpriteAnimationFinished {
+    #[expect(dead_code, reason = "nothing reacts to finished animations yet")]
+    pub entity: Entity,
+}
+
+/// Written when a `SpriteAnimation` shows a frame it has a named event on.
+#[derive(Message, Debug, Clone, Copy)]
+pub struct SpriteFrameEvent {
+    pub entity: Entity,
+    pub name: &'static str,
+}
@@ -1,5 +1,6 @@
 pub mod components;
 pub mod layers;
+pub mod messages;
 mod plugin;
 mod systems;
 
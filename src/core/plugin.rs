@@ -1,4 +1,5 @@
-use super::components::{Score, Speed, SpriteAnimation};
+use super::components::{AnimationMode, Score, Speed, SpriteAnimation};
+use super::messages::{SpriteAnimationFinished, SpriteFrameEvent};
 use super::systems::{animate_sprites, increment_score};
 use bevy::prelude::*;
 
@@ -10,6 +11,9 @@ impl Plugin for CorePlugin {
             .register_type::<Score>()
             .register_type::<Speed>()
             .register_type::<SpriteAnimation>()
+            .register_type::<AnimationMode>()
+            .add_message::<SpriteAnimationFinished>()
+            .add_message::<SpriteFrameEvent>()
             .add_systems(Update, (animate_sprites, increment_score));
     }
 }
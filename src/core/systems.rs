@@ -1,27 +1,58 @@
 use super::components::{Score, SpriteAnimation};
-use crate::pickups::messages::CoinCollected;
+use super::messages::{SpriteAnimationFinished, SpriteFrameEvent};
+use crate::pickups::components::PickupKind;
+use crate::pickups::messages::PickupCollected;
 use bevy::prelude::*;
+use std::time::Duration;
 
-/// Ticks sprite animations and advances frames.
-pub fn animate_sprites(time: Res<Time>, mut query: Query<(&mut SpriteAnimation, &mut Sprite)>) {
-    for (mut anim, mut sprite) in &mut query {
-        anim.timer.tick(time.delta());
+/// Ticks sprite animations and advances frames, writing finished and frame event messages.
+///
+/// Time left over past a frame's duration carries into the next frame, so playback keeps
+/// its rate regardless of the frame rate, skipping frames if a tick covers several.
+pub fn animate_sprites(
+    time: Res<Time>,
+    mut query: Query<(Entity, &mut SpriteAnimation, &mut Sprite)>,
+    mut finished: MessageWriter<SpriteAnimationFinished>,
+    mut frame_events: MessageWriter<SpriteFrameEvent>,
+) {
+    for (entity, mut anim, mut sprite) in &mut query {
+        if anim.finished {
+            continue;
+        }
+        let Some(atlas) = &mut sprite.texture_atlas else {
+            continue;
+        };
+
+        // Not `Timer::tick`, which clamps the elapsed time to the duration in once mode
+        let mut elapsed = anim.timer.elapsed() + time.delta();
+        while elapsed >= anim.timer.duration() && !anim.timer.duration().is_zero() {
+            elapsed -= anim.timer.duration();
 
-        if anim.timer.just_finished()
-            && let Some(atlas) = &mut sprite.texture_atlas
-        {
-            atlas.index = if atlas.index >= anim.last {
-                anim.first
-            } else {
-                atlas.index + 1
+            let Some(next) = anim.next_frame(atlas.index) else {
+                anim.finished = true;
+                finished.write(SpriteAnimationFinished { entity });
+                break;
             };
+            atlas.index = next;
+
+            let duration = anim.frame_duration(next);
+            anim.timer.set_duration(Duration::from_secs_f32(duration));
+
+            for &(frame, name) in &anim.events {
+                if frame == next {
+                    frame_events.write(SpriteFrameEvent { entity, name });
+                }
+            }
         }
+        anim.timer.set_elapsed(elapsed);
     }
 }
 
-/// Increments score when coins are collected.
-pub fn increment_score(mut messages: MessageReader<CoinCollected>, mut score: ResMut<Score>) {
-    for _ in messages.read() {
-        score.0 += 1;
+/// Adds the value of collected coins to the score.
+pub fn increment_score(mut messages: MessageReader<PickupCollected>, mut score: ResMut<Score>) {
+    for evt in messages.read() {
+        if evt.kind == PickupKind::Coin {
+            score.0 += evt.value;
+        }
     }
 }
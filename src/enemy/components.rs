@@ -1,4 +1,5 @@
-use crate::core::components::{Speed, SpriteAnimation};
+use crate::aseprite::components::AsepriteAnimation;
+use crate::core::components::Speed;
 use crate::core::layers::GameLayer;
 use crate::killzone::components::KillZone;
 use avian2d::prelude::*;
@@ -19,6 +20,7 @@ use bevy::{
     Sensor,
     CollisionEventsEnabled,
     KillZone,
+    WaterBehavior,
     RayCaster = RayCaster::new(Vec2::ZERO, Dir2::X)
         .with_max_hits(1)
         .with_max_distance(7.0)
@@ -26,13 +28,23 @@ use bevy::{
 )]
 pub struct Enemy;
 
+/// How an enemy reacts to water volumes.
+#[derive(Component, Reflect, Default, Debug, Clone, Copy, PartialEq, Eq)]
+#[reflect(Component, Default)]
+pub enum WaterBehavior {
+    /// Turns around at the water's edge instead of walking in.
+    #[default]
+    Avoid,
+    /// Bobs on the surface, patrolling across the water.
+    Float,
+}
+
 #[derive(Component, Default, Reflect)]
 #[reflect(Component, Default)]
 #[require(
     Enemy,
     Sprite,
     Anchor = Anchor::from(Vec2::new(0.0, -0.25)),
-    SpriteAnimation = SpriteAnimation::new(4, 7, 10),  // Second row, 10fps
 )]
 #[component(on_add = Self::on_add)]
 pub struct Slime;
@@ -43,22 +55,16 @@ impl Slime {
 
         let asset_server = world.resource::<AssetServer>();
         let texture = asset_server.load("sprites/slime_green.png");
-
-        let mut layouts = world.resource_mut::<Assets<TextureAtlasLayout>>();
-        let layout = layouts.add(TextureAtlasLayout::from_grid(
-            UVec2::splat(24),
-            4,
-            3,
-            None,
-            None,
-        ));
+        let layout = asset_server.load("sprites/slime_green.aseprite.json#layout");
+        let sheet = asset_server.load("sprites/slime_green.aseprite.json");
 
         if let Some(mut sprite) = world.get_mut::<Sprite>(entity) {
             sprite.image = texture;
-            sprite.texture_atlas = Some(TextureAtlas {
-                layout,
-                index: 4, // Second row, first frame
-            });
+            sprite.texture_atlas = Some(TextureAtlas { layout, index: 0 });
         }
+        world
+            .commands()
+            .entity(entity)
+            .insert(AsepriteAnimation::new(sheet, "walk"));
     }
 }
@@ -0,0 +1,8 @@
+use bevy::prelude::*;
+
+/// Written when an enemy is defeated, just before it is despawned.
+#[derive(Message, Debug, Clone, Copy)]
+pub struct EnemyDefeated {
+    /// World position of the enemy
+    pub position: Vec2,
+}
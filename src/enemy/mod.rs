@@ -1,5 +1,7 @@
 mod components;
+pub mod messages;
 mod plugin;
 mod systems;
 
+pub use components::Enemy;
 pub use plugin::EnemyPlugin;
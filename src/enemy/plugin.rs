@@ -1,5 +1,9 @@
-use super::components::{Enemy, Slime};
-use super::systems::{spawn_slime_at_spawn_point, update_patrol_movement};
+use super::components::{Enemy, Slime, WaterBehavior};
+use super::messages::EnemyDefeated;
+use super::systems::{
+    apply_water_behavior, carry_enemies_on_platforms, spawn_slime_at_spawn_point,
+    update_patrol_movement,
+};
 use bevy::prelude::*;
 
 pub struct EnemyPlugin;
@@ -8,7 +12,17 @@ impl Plugin for EnemyPlugin {
     fn build(&self, app: &mut App) {
         app.register_type::<Enemy>()
             .register_type::<Slime>()
+            .register_type::<WaterBehavior>()
+            .add_message::<EnemyDefeated>()
             .add_systems(Update, spawn_slime_at_spawn_point)
-            .add_systems(FixedUpdate, update_patrol_movement);
+            .add_systems(
+                FixedUpdate,
+                (
+                    update_patrol_movement,
+                    carry_enemies_on_platforms,
+                    apply_water_behavior,
+                )
+                    .chain(),
+            );
     }
 }
@@ -1,6 +1,8 @@
-use super::components::{Enemy, Slime};
+use super::components::{Enemy, Slime, WaterBehavior};
 use crate::core::components::Speed;
+use crate::platform::MovingPlatform;
 use crate::state::GameState;
+use crate::water::components::Water;
 use avian2d::prelude::*;
 use bevy::prelude::*;
 use bevy_ecs_tiled::prelude::*;
@@ -54,3 +56,91 @@ pub fn update_patrol_movement(
         sprite.flip_x = ray_caster.direction.x < 0.0;
     }
 }
+
+/// Moves enemies along with moving platforms they stand on. Falling crumbling platforms
+/// are kinematic too, but have their collider disabled and carry nothing.
+pub fn carry_enemies_on_platforms(
+    mut enemies: Query<(&ColliderAabb, &mut LinearVelocity), With<Enemy>>,
+    platforms: Query<
+        (&RigidBody, &ColliderAabb, &LinearVelocity),
+        (
+            With<MovingPlatform>,
+            Without<ColliderDisabled>,
+            Without<Enemy>,
+        ),
+    >,
+) {
+    for (enemy_aabb, mut velocity) in &mut enemies {
+        let carry = platforms
+            .iter()
+            .filter(|(body, ..)| body.is_kinematic())
+            .find(|(_, aabb, _)| {
+                let overlaps_x = enemy_aabb.min.x < aabb.max.x && enemy_aabb.max.x > aabb.min.x;
+                overlaps_x && (enemy_aabb.min.y - aabb.max.y).abs() <= 2.0
+            })
+            .map(|(.., platform_vel)| platform_vel.0)
+            .unwrap_or(Vec2::ZERO);
+
+        velocity.x += carry.x;
+        velocity.y = carry.y;
+    }
+}
+
+/// Turns water-avoiding enemies around at the water's edge and keeps floating ones
+/// on the surface.
+pub fn apply_water_behavior(
+    mut enemies: Query<
+        (
+            &WaterBehavior,
+            &ColliderAabb,
+            &mut RayCaster,
+            &mut LinearVelocity,
+            &mut Sprite,
+        ),
+        With<Enemy>,
+    >,
+    colliders: Query<(&TiledColliderOf, &ColliderAabb)>,
+    water: Query<(), With<Water>>,
+) {
+    let volumes: Vec<&ColliderAabb> = colliders
+        .iter()
+        .filter(|(owner, _)| water.contains(owner.0))
+        .map(|(_, aabb)| aabb)
+        .collect();
+    if volumes.is_empty() {
+        return;
+    }
+
+    for (behavior, aabb, mut ray_caster, mut velocity, mut sprite) in &mut enemies {
+        let feet = Vec2::new(aabb.center().x, aabb.min.y);
+        match behavior {
+            WaterBehavior::Avoid => {
+                // Probe just ahead of and below the feet
+                let ahead = feet + Vec2::new(ray_caster.direction.x * aabb.size().x, -2.0);
+                let in_water = |volume: &&ColliderAabb| {
+                    ahead.x >= volume.min.x
+                        && ahead.x <= volume.max.x
+                        && ahead.y >= volume.min.y
+                        && ahead.y <= volume.max.y
+                };
+                if volumes.iter().any(in_water) {
+                    ray_caster.direction = -ray_caster.direction;
+                    velocity.x = -velocity.x;
+                    sprite.flip_x = ray_caster.direction.x < 0.0;
+                }
+            }
+            WaterBehavior::Float => {
+                let surface = volumes
+                    .iter()
+                    .filter(|v| feet.x >= v.min.x && feet.x <= v.max.x)
+                    .filter(|v| feet.y >= v.min.y && feet.y < v.max.y)
+                    .map(|v| v.max.y)
+                    .reduce(f32::max);
+                if let Some(surface) = surface {
+                    // Rise toward the surface, easing in as the feet reach it
+                    velocity.y = ((surface - feet.y) * 4.0).min(40.0);
+                }
+            }
+        }
+    }
+}
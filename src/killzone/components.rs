@@ -13,3 +13,27 @@ impl Default for DeathTimer {
         Self(Timer::from_seconds(0.6, TimerMode::Once))
     }
 }
+
+/// Player is holding the respawn pose; input is ignored until the timer finishes.
+#[derive(Component, Reflect)]
+#[reflect(Component)]
+pub struct Respawning(pub Timer);
+
+/// Full-screen overlay fading out to a level reload after death, then back in.
+/// Outlives the level, so it is not despawned on leaving `GameState::Playing`.
+#[derive(Component, Reflect)]
+#[reflect(Component)]
+pub struct ScreenFade {
+    pub phase: FadePhase,
+    pub timer: Timer,
+}
+
+#[derive(Reflect, Debug, Clone, Copy, PartialEq, Eq)]
+pub enum FadePhase {
+    /// Darkening, reloading the level when done
+    Out,
+    /// Black until the respawned player appears
+    Hold,
+    /// Revealing the respawned player
+    In,
+}
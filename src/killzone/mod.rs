@@ -3,3 +3,4 @@ mod plugin;
 mod systems;
 
 pub use plugin::KillZonePlugin;
+pub use systems::start_death;
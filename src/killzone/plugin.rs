@@ -1,5 +1,8 @@
-use super::components::{DeathTimer, KillZone};
-use super::systems::{detect_killzone_collision, setup_killzone_sensors, tick_death_timer};
+use super::components::{DeathTimer, FadePhase, KillZone, Respawning, ScreenFade};
+use super::systems::{
+    begin_death_sequence, detect_killzone_collision, setup_killzone_sensors, tick_death_timer,
+    tick_respawning, update_screen_fade,
+};
 use bevy::prelude::*;
 
 pub struct KillZonePlugin;
@@ -8,12 +11,21 @@ impl Plugin for KillZonePlugin {
     fn build(&self, app: &mut App) {
         app.register_type::<KillZone>()
             .register_type::<DeathTimer>()
+            .register_type::<Respawning>()
+            .register_type::<ScreenFade>()
+            .register_type::<FadePhase>()
             .add_systems(
                 Update,
                 (
                     setup_killzone_sensors,
-                    detect_killzone_collision,
-                    tick_death_timer,
+                    (
+                        detect_killzone_collision,
+                        begin_death_sequence,
+                        tick_death_timer,
+                    )
+                        .chain(),
+                    update_screen_fade,
+                    tick_respawning,
                 ),
             );
     }
@@ -1,9 +1,12 @@
-use super::components::{DeathTimer, KillZone};
-use crate::player::Player;
+use super::components::{DeathTimer, FadePhase, KillZone, Respawning, ScreenFade};
+use crate::player::{Dashing, Player, PlayerTuning};
+use crate::powerups::components::Invulnerable;
 use crate::state::GameState;
+use crate::time_scale::resources::{TimeScale, TimeScalePriority};
 use avian2d::prelude::*;
 use bevy::prelude::*;
 use bevy_ecs_tiled::prelude::*;
+use std::time::Duration;
 
 /// Makes kill zone colliders into sensors when created by bevy_ecs_tiled.
 pub fn setup_killzone_sensors(
@@ -25,9 +28,8 @@ pub fn detect_killzone_collision(
     mut collision_events: MessageReader<CollisionStart>,
     mut commands: Commands,
     killzones: Query<(), With<KillZone>>,
-    players: Query<Entity, (With<Player>, Without<DeathTimer>)>,
+    players: Query<(Has<Invulnerable>, Option<&Dashing>), (With<Player>, Without<DeathTimer>)>,
     collider_query: Query<&TiledColliderOf>,
-    mut time: ResMut<Time<Virtual>>,
 ) {
     for evt in collision_events.read() {
         // Check if either collider belongs to a kill zone (via TiledColliderOf parent)
@@ -56,30 +58,147 @@ pub fn detect_killzone_collision(
             .flatten()
             .find(|&body| players.contains(body));
 
+        // Invulnerability and dashing through enemies only protect against enemies,
+        // not falling out of the level
+        let invulnerable = player_entity.and_then(|p| players.get(p).ok()).is_some_and(
+            |(invulnerable, dashing)| invulnerable || dashing.is_some_and(|d| d.through_enemies),
+        );
+        if invulnerable && killzone_direct {
+            continue;
+        }
+
         if let Some(player) = player_entity {
             info!("Player hit kill zone! Starting death timer...");
-            time.set_relative_speed(0.5);
-            commands
-                .entity(player)
-                .remove::<Collider>()
-                .insert(DeathTimer::default());
+            start_death(&mut commands, player);
         }
     }
 }
 
-/// Ticks death timer and transitions to Reloading state when it expires.
+/// Starts the player's death sequence, ending in a level reload.
+pub fn start_death(commands: &mut Commands, player: Entity) {
+    commands
+        .entity(player)
+        .remove::<Collider>()
+        .insert(DeathTimer::default());
+}
+
+/// Name of the death slow-motion time-scale request
+const DEATH_SLOW_MOTION: &str = "death";
+
+/// Slows time and pops the dying player upward, timed by the player tuning.
+/// Gravity is restored since movement systems no longer run for the player.
+pub fn begin_death_sequence(
+    tuning: Res<PlayerTuning>,
+    mut time_scale: ResMut<TimeScale>,
+    mut players: Query<
+        (&mut DeathTimer, &mut LinearVelocity, &mut GravityScale),
+        Added<DeathTimer>,
+    >,
+) {
+    for (mut timer, mut velocity, mut gravity_scale) in &mut players {
+        time_scale.push(
+            DEATH_SLOW_MOTION,
+            tuning.death_time_scale,
+            TimeScalePriority::Gameplay,
+            None,
+        );
+        timer
+            .0
+            .set_duration(Duration::from_secs_f32(tuning.death_hold_time));
+        velocity.0 = Vec2::new(0.0, tuning.death_pop_velocity);
+        gravity_scale.0 = 1.0;
+    }
+}
+
+/// Ticks death timer and starts fading the screen out when it expires.
 pub fn tick_death_timer(
+    mut commands: Commands,
     time: Res<Time>,
-    mut virtual_time: ResMut<Time<Virtual>>,
+    tuning: Res<PlayerTuning>,
+    mut time_scale: ResMut<TimeScale>,
     mut death_query: Query<&mut DeathTimer>,
-    mut next_state: ResMut<NextState<GameState>>,
 ) {
     for mut timer in &mut death_query {
         timer.0.tick(time.delta());
 
         if timer.0.just_finished() {
-            virtual_time.set_relative_speed(1.0);
-            next_state.set(GameState::Reloading);
+            time_scale.remove(DEATH_SLOW_MOTION);
+            commands.spawn((
+                Name::new("Screen Fade"),
+                ScreenFade {
+                    phase: FadePhase::Out,
+                    timer: Timer::from_seconds(tuning.fade_out_time, TimerMode::Once),
+                },
+                Node {
+                    position_type: PositionType::Absolute,
+                    width: Val::Percent(100.0),
+                    height: Val::Percent(100.0),
+                    ..default()
+                },
+                BackgroundColor(Color::NONE),
+                GlobalZIndex(100),
+            ));
+        }
+    }
+}
+
+/// Fades the screen out and reloads the level, then fades back in once the new player
+/// spawns, putting it in the respawn pose.
+pub fn update_screen_fade(
+    mut commands: Commands,
+    time: Res<Time>,
+    tuning: Res<PlayerTuning>,
+    mut fades: Query<(Entity, &mut ScreenFade, &mut BackgroundColor)>,
+    spawned: Query<Entity, Added<Player>>,
+    mut next_state: ResMut<NextState<GameState>>,
+) {
+    for (entity, mut fade, mut background) in &mut fades {
+        fade.timer.tick(time.delta());
+
+        let alpha = match fade.phase {
+            FadePhase::Out => {
+                if fade.timer.is_finished() {
+                    next_state.set(GameState::Reloading);
+                    fade.phase = FadePhase::Hold;
+                }
+                fade.timer.fraction()
+            }
+            FadePhase::Hold => {
+                for player in &spawned {
+                    commands
+                        .entity(player)
+                        .insert(Respawning(Timer::from_seconds(
+                            tuning.respawn_pose_time,
+                            TimerMode::Once,
+                        )));
+                    fade.phase = FadePhase::In;
+                    fade.timer = Timer::from_seconds(tuning.fade_in_time, TimerMode::Once);
+                }
+                1.0
+            }
+            FadePhase::In => {
+                if fade.timer.is_finished() {
+                    commands.entity(entity).despawn();
+                    continue;
+                }
+                fade.timer.fraction_remaining()
+            }
+        };
+
+        background.0 = Color::BLACK.with_alpha(alpha);
+    }
+}
+
+/// Returns control to the player once the respawn pose is over.
+pub fn tick_respawning(
+    mut commands: Commands,
+    time: Res<Time>,
+    mut players: Query<(Entity, &mut Respawning)>,
+) {
+    for (entity, mut respawning) in &mut players {
+        respawning.0.tick(time.delta());
+        if respawning.0.is_finished() {
+            commands.entity(entity).remove::<Respawning>();
         }
     }
 }
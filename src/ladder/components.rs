@@ -0,0 +1,16 @@
+use bevy::prelude::*;
+
+/// Climbable zone. Add to a Tiled rectangle object covering the ladder; its top edge
+/// should line up with the top of any one-way platform the ladder leads onto.
+#[derive(Component, Reflect, Debug, Clone)]
+#[reflect(Component, Default)]
+pub struct Ladder {
+    /// Climbing speed in pixels per second
+    pub speed: f32,
+}
+
+impl Default for Ladder {
+    fn default() -> Self {
+        Self { speed: 60.0 }
+    }
+}
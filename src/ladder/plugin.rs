@@ -0,0 +1,17 @@
+use super::components::Ladder;
+use super::systems::{setup_ladder_sensors, update_ladder_contact};
+use crate::player::PlayerSystemSet;
+use bevy::prelude::*;
+
+pub struct LadderPlugin;
+
+impl Plugin for LadderPlugin {
+    fn build(&self, app: &mut App) {
+        app.register_type::<Ladder>()
+            .add_systems(Update, setup_ladder_sensors)
+            .add_systems(
+                FixedUpdate,
+                update_ladder_contact.before(PlayerSystemSet::Movement),
+            );
+    }
+}
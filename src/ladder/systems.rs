@@ -0,0 +1,61 @@
+use super::components::Ladder;
+use crate::core::layers::GameLayer;
+use crate::player::{OnLadder, PLAYER_HALF_HEIGHT, Player};
+use avian2d::prelude::*;
+use bevy::prelude::*;
+use bevy_ecs_tiled::prelude::*;
+
+/// Slack above a ladder's top edge, so a player standing on top can climb down
+const TOP_SLACK: f32 = 2.0;
+
+/// Makes ladder colliders into sensors when created by bevy_ecs_tiled.
+/// They leave the Wall layer so enemy wall probes don't turn around at ladders.
+pub fn setup_ladder_sensors(
+    mut collider_events: MessageReader<TiledEvent<ColliderCreated>>,
+    ladders: Query<(), With<Ladder>>,
+    mut commands: Commands,
+) {
+    for evt in collider_events.read() {
+        if ladders.contains(*evt.event.collider_of) {
+            commands.entity(evt.origin).insert((
+                Sensor,
+                CollisionLayers::new(GameLayer::Default, LayerMask::ALL),
+            ));
+        }
+    }
+}
+
+/// Tracks which ladder, if any, the player's center and feet are within.
+pub fn update_ladder_contact(
+    mut commands: Commands,
+    players: Query<(Entity, &Position, Option<&OnLadder>), With<Player>>,
+    colliders: Query<(&TiledColliderOf, &ColliderAabb)>,
+    ladders: Query<&Ladder>,
+) {
+    for (player, position, current) in &players {
+        let feet = position.y - PLAYER_HALF_HEIGHT;
+
+        let ladder = colliders.iter().find_map(|(owner, aabb)| {
+            let ladder = ladders.get(owner.0).ok()?;
+            let inside = position.x >= aabb.min.x
+                && position.x <= aabb.max.x
+                && feet >= aabb.min.y
+                && feet <= aabb.max.y + TOP_SLACK;
+            inside.then_some(OnLadder {
+                speed: ladder.speed,
+                at_bottom: feet <= aabb.min.y + 1.0,
+                at_top: feet >= aabb.max.y,
+            })
+        });
+
+        match ladder {
+            Some(ladder) => {
+                commands.entity(player).insert(ladder);
+            }
+            None if current.is_some() => {
+                commands.entity(player).remove::<OnLadder>();
+            }
+            None => {}
+        }
+    }
+}
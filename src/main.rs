@@ -1,29 +1,51 @@
+mod abilities;
+mod aseprite;
 mod audio;
 mod camera;
 mod core;
 mod enemy;
 mod killzone;
+mod ladder;
+mod parallax;
+mod particles;
 mod physics;
 mod pickups;
 mod platform;
 mod player;
+mod powerups;
+mod puzzle;
+mod save;
 pub mod state;
+mod surface;
 mod tiled;
+mod time_scale;
 mod tips;
+mod water;
 
+use abilities::AbilitiesPlugin;
+use aseprite::AsepritePlugin;
 use audio::AudioPlugin;
 use bevy::{prelude::*, window::WindowResolution};
 use camera::CameraPlugin;
 use core::CorePlugin;
 use enemy::EnemyPlugin;
 use killzone::KillZonePlugin;
+use ladder::LadderPlugin;
+use parallax::ParallaxPlugin;
+use particles::ParticlesPlugin;
 use physics::PhysicsPlugin;
 use pickups::PickupsPlugin;
 use platform::PlatformPlugin;
 use player::PlayerPlugin;
+use powerups::PowerUpsPlugin;
+use puzzle::PuzzlePlugin;
+use save::SavePlugin;
 use state::{GameState, restart_game};
+use surface::SurfacePlugin;
 use tiled::TiledPlugin;
+use time_scale::TimeScalePlugin;
 use tips::TipsPlugin;
+use water::WaterPlugin;
 
 fn main() -> AppExit {
     let mut app = App::new();
@@ -43,14 +65,27 @@ fn main() -> AppExit {
         TiledPlugin,
         PhysicsPlugin,
         CorePlugin,
+        AsepritePlugin,
         AudioPlugin,
+        SavePlugin,
+        AbilitiesPlugin,
+        TimeScalePlugin,
+    ))
+    .add_plugins((
         PlayerPlugin,
         CameraPlugin,
         PlatformPlugin,
+        SurfacePlugin,
+        LadderPlugin,
+        WaterPlugin,
         PickupsPlugin,
+        PowerUpsPlugin,
+        PuzzlePlugin,
         KillZonePlugin,
         EnemyPlugin,
         TipsPlugin,
+        ParticlesPlugin,
+        ParallaxPlugin,
     ))
     .init_state::<GameState>()
     .add_systems(OnEnter(GameState::Reloading), restart_game);
@@ -0,0 +1,28 @@
+use bevy::prelude::*;
+
+/// Extra parallax settings for a Tiled layer, set as a custom property on the layer.
+/// The parallax factor itself comes from the layer's native Tiled parallax, and image
+/// layers repeat with Tiled's native "Repeat X".
+#[derive(Component, Reflect, Default, Debug, Clone)]
+#[reflect(Component, Default)]
+pub struct ParallaxLayer {
+    /// Horizontal auto-scroll in pixels per second, e.g. for drifting clouds.
+    /// Usually set on an image layer with "Repeat X".
+    pub scroll_speed: f32,
+}
+
+/// Moves a layer relative to the `FollowCamera` by its parallax factor.
+#[derive(Component, Debug)]
+pub struct Parallax {
+    /// Fraction of camera movement the layer follows (1 = moves with the world)
+    pub factor: Vec2,
+    /// Local translation of the layer as spawned
+    pub base: Vec2,
+    /// Map the layer belongs to
+    pub map: Entity,
+    /// Offset from the map entity to the map's top-left corner, the parallax origin
+    pub origin_offset: Vec2,
+    pub scroll_speed: f32,
+    /// Auto-scroll distance so far
+    pub scroll: f32,
+}
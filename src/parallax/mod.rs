@@ -0,0 +1,5 @@
+pub mod components;
+mod plugin;
+mod systems;
+
+pub use plugin::ParallaxPlugin;
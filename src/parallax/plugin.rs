@@ -0,0 +1,17 @@
+use super::components::ParallaxLayer;
+use super::systems::{setup_parallax_layers, update_parallax};
+use bevy::prelude::*;
+
+pub struct ParallaxPlugin;
+
+impl Plugin for ParallaxPlugin {
+    fn build(&self, app: &mut App) {
+        app.register_type::<ParallaxLayer>()
+            .add_systems(Update, setup_parallax_layers)
+            // After the camera has followed the player, before transforms propagate
+            .add_systems(
+                PostUpdate,
+                update_parallax.before(TransformSystems::Propagate),
+            );
+    }
+}
@@ -0,0 +1,98 @@
+use super::components::{Parallax, ParallaxLayer};
+use crate::camera::FollowCamera;
+use bevy::prelude::*;
+use bevy_ecs_tiled::prelude::*;
+
+/// Gives Tiled layers with a parallax factor other than 1, or an auto-scroll, a `Parallax`.
+///
+/// Replaces bevy_ecs_tiled's own `TiledLayerParallax`, which measures from the world origin
+/// rather than the map's top-left corner as Tiled does.
+pub fn setup_parallax_layers(
+    mut commands: Commands,
+    mut layer_events: MessageReader<TiledEvent<LayerCreated>>,
+    map_assets: Res<Assets<TiledMapAsset>>,
+    layers: Query<(&Transform, Option<&ParallaxLayer>)>,
+    parents: Query<&ChildOf>,
+    maps: Query<(&TiledMap, &TilemapAnchor)>,
+) {
+    for evt in layer_events.read() {
+        let Some(layer) = evt.get_layer(&map_assets) else {
+            continue;
+        };
+        let Ok((transform, settings)) = layers.get(evt.origin) else {
+            continue;
+        };
+
+        let factor = Vec2::new(layer.parallax_x, layer.parallax_y);
+        let settings = settings.cloned().unwrap_or_default();
+        if factor == Vec2::ONE && settings.scroll_speed == 0.0 {
+            continue;
+        }
+
+        let Some((map, tiled_map, anchor)) = parents
+            .iter_ancestors(evt.origin)
+            .find_map(|entity| maps.get(entity).ok().map(|(m, a)| (entity, m, a)))
+        else {
+            continue;
+        };
+        let Some(map_asset) = map_assets.get(&tiled_map.0) else {
+            continue;
+        };
+        let size = Vec2::new(
+            (map_asset.map.width * map_asset.map.tile_width) as f32,
+            (map_asset.map.height * map_asset.map.tile_height) as f32,
+        );
+        // Tiled measures parallax from the map's top-left corner
+        let origin_offset = match anchor {
+            TilemapAnchor::Center => Vec2::new(-size.x / 2.0, size.y / 2.0),
+            TilemapAnchor::TopLeft => Vec2::ZERO,
+            TilemapAnchor::BottomLeft => Vec2::new(0.0, size.y),
+            other => {
+                warn!("Parallax layers are not supported on maps with anchor {other:?}");
+                continue;
+            }
+        };
+
+        commands
+            .entity(evt.origin)
+            .remove::<TiledLayerParallax>()
+            .insert(Parallax {
+                factor,
+                base: transform.translation.truncate(),
+                map,
+                origin_offset,
+                scroll_speed: settings.scroll_speed,
+                scroll: 0.0,
+            });
+    }
+}
+
+/// Offsets parallax layers by the camera's distance from the map's parallax origin,
+/// scaled by `1 - factor`, plus any auto-scroll. bevy_ecs_tiled keeps repeating images
+/// covering the view.
+pub fn update_parallax(
+    time: Res<Time>,
+    camera: Query<&Transform, (With<FollowCamera>, Without<Parallax>)>,
+    maps: Query<&GlobalTransform, With<TiledMap>>,
+    mut layers: Query<(&mut Parallax, &mut Transform)>,
+) {
+    let Ok(camera) = camera.single() else {
+        return;
+    };
+    let camera = camera.translation.truncate();
+
+    for (mut parallax, mut transform) in &mut layers {
+        let Ok(map) = maps.get(parallax.map) else {
+            continue;
+        };
+        let origin = map.translation().truncate() + parallax.origin_offset;
+
+        parallax.scroll += parallax.scroll_speed * time.delta_secs();
+        let mut offset = (camera - origin) * (Vec2::ONE - parallax.factor);
+        offset.x += parallax.scroll;
+
+        let translation = parallax.base + offset;
+        transform.translation.x = translation.x;
+        transform.translation.y = translation.y;
+    }
+}
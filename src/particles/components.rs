@@ -0,0 +1,35 @@
+use super::effect::ParticleEffect;
+use bevy::prelude::*;
+
+/// A live particle, simulated by `update_particles` from its effect's settings.
+#[derive(Component, Debug)]
+pub struct Particle {
+    pub effect: AssetId<ParticleEffect>,
+    pub velocity: Vec2,
+    /// Seconds since spawning
+    pub age: f32,
+    /// Seconds until despawning
+    pub lifetime: f32,
+}
+
+/// Emits bursts of an effect at the effect's interval while active, following its entity.
+#[derive(Component, Debug)]
+pub struct ParticleEmitter {
+    pub effect: Handle<ParticleEffect>,
+    /// Emission point relative to the entity
+    pub offset: Vec2,
+    pub active: bool,
+    /// Seconds since the last burst
+    pub elapsed: f32,
+}
+
+impl ParticleEmitter {
+    pub fn new(effect: Handle<ParticleEffect>, offset: Vec2) -> Self {
+        Self {
+            effect,
+            offset,
+            active: false,
+            elapsed: 0.0,
+        }
+    }
+}
@@ -0,0 +1,165 @@
+use bevy::{
+    asset::{AssetLoader, LoadContext, io::Reader},
+    prelude::*,
+};
+use serde::Deserialize;
+
+/// A particle effect, loaded from a `.particle.ron` file under `assets/particles/`.
+///
+/// Particles are plain sprites simulated on the CPU. Edits to the file apply to
+/// particles spawned afterwards (hot-reloaded with the `debug` feature's file watcher).
+#[derive(Asset, TypePath, Deserialize, Debug, Clone)]
+#[serde(default)]
+pub struct ParticleEffect {
+    /// Particles spawned per burst
+    pub count: u32,
+    /// Seconds between bursts of a continuous emitter. Unused by one-shot effects.
+    pub interval: f32,
+    /// Lifetime range in seconds
+    pub lifetime: (f32, f32),
+    /// Initial speed range in pixels per second
+    pub speed: (f32, f32),
+    /// Launch direction in degrees, counterclockwise from +X (90 = up)
+    pub direction: f32,
+    /// Total width in degrees of the cone around `direction`
+    pub spread: f32,
+    /// Half extents of the box particles spawn in, in pixels
+    pub area: (f32, f32),
+    /// Vertical acceleration in pixels per second squared (negative = down)
+    pub gravity: f32,
+    /// Fraction of velocity lost per second
+    pub drag: f32,
+    /// Sprite size in pixels
+    pub size: f32,
+    /// Color over normalized lifetime, as sorted stops
+    pub colors: Vec<ColorStop>,
+    /// Atlas to draw frames from. Without one, particles are solid squares.
+    pub atlas: Option<ParticleAtlas>,
+    #[serde(skip)]
+    #[dependency]
+    pub image: Option<Handle<Image>>,
+    #[serde(skip)]
+    pub layout: Option<Handle<TextureAtlasLayout>>,
+}
+
+impl Default for ParticleEffect {
+    fn default() -> Self {
+        Self {
+            count: 8,
+            interval: 0.1,
+            lifetime: (0.3, 0.5),
+            speed: (20.0, 40.0),
+            direction: 90.0,
+            spread: 180.0,
+            area: (0.0, 0.0),
+            gravity: 0.0,
+            drag: 0.0,
+            size: 2.0,
+            colors: vec![ColorStop {
+                at: 0.0,
+                color: (1.0, 1.0, 1.0, 1.0),
+            }],
+            atlas: None,
+            image: None,
+            layout: None,
+        }
+    }
+}
+
+impl ParticleEffect {
+    /// Color at `t` (0..1) through the particle's life.
+    pub fn color_at(&self, t: f32) -> Color {
+        let Some(first) = self.colors.first() else {
+            return Color::WHITE;
+        };
+
+        let mut color = first.color();
+        for pair in self.colors.windows(2) {
+            let (a, b) = (&pair[0], &pair[1]);
+            if t <= a.at {
+                break;
+            }
+            color = if t < b.at {
+                a.color().mix(&b.color(), (t - a.at) / (b.at - a.at))
+            } else {
+                b.color()
+            };
+        }
+        color
+    }
+
+    /// Atlas index at `t` (0..1) through the particle's life, stepping evenly through the frames.
+    pub fn frame_at(&self, t: f32) -> Option<usize> {
+        let (first, last) = self.atlas.as_ref()?.frames;
+        let frames = last.saturating_sub(first) + 1;
+        Some(first + ((t * frames as f32) as usize).min(frames - 1))
+    }
+}
+
+/// A point on an effect's color gradient.
+#[derive(Deserialize, Debug, Clone)]
+pub struct ColorStop {
+    /// Normalized lifetime (0..1)
+    pub at: f32,
+    /// sRGBA color
+    pub color: (f32, f32, f32, f32),
+}
+
+impl ColorStop {
+    fn color(&self) -> Color {
+        let (r, g, b, a) = self.color;
+        Color::srgba(r, g, b, a)
+    }
+}
+
+/// Sprite sheet a particle effect animates through.
+#[derive(Deserialize, Debug, Clone)]
+pub struct ParticleAtlas {
+    /// Asset path of the image
+    pub image: String,
+    /// Size of one frame in pixels
+    pub tile_size: (u32, u32),
+    pub columns: u32,
+    pub rows: u32,
+    /// First and last atlas index played over the particle's life
+    pub frames: (usize, usize),
+}
+
+/// Loads `.particle.ron` files as `ParticleEffect`, along with their atlas image.
+#[derive(Default, TypePath)]
+pub struct ParticleEffectLoader;
+
+impl AssetLoader for ParticleEffectLoader {
+    type Asset = ParticleEffect;
+    type Settings = ();
+    type Error = BevyError;
+
+    async fn load(
+        &self,
+        reader: &mut dyn Reader,
+        _settings: &(),
+        load_context: &mut LoadContext<'_>,
+    ) -> Result<Self::Asset, Self::Error> {
+        let mut bytes = Vec::new();
+        reader.read_to_end(&mut bytes).await?;
+        let mut effect: ParticleEffect = ron::de::from_bytes(&bytes)?;
+
+        if let Some(atlas) = &effect.atlas {
+            let layout = TextureAtlasLayout::from_grid(
+                UVec2::new(atlas.tile_size.0, atlas.tile_size.1),
+                atlas.columns,
+                atlas.rows,
+                None,
+                None,
+            );
+            effect.image = Some(load_context.load(atlas.image.clone()));
+            effect.layout = Some(load_context.add_labeled_asset("layout".to_string(), layout));
+        }
+
+        Ok(effect)
+    }
+
+    fn extensions(&self) -> &[&str] {
+        &["particle.ron"]
+    }
+}
@@ -0,0 +1,12 @@
+use super::effect::ParticleEffect;
+use bevy::prelude::*;
+
+/// Spawns one burst of a particle effect. Gameplay code writes this to show an effect.
+#[derive(Message, Debug, Clone)]
+pub struct SpawnParticles {
+    pub effect: Handle<ParticleEffect>,
+    /// World position of the burst
+    pub position: Vec2,
+    /// Mirror the launch direction horizontally, e.g. for a left-facing emitter
+    pub mirror: bool,
+}
@@ -0,0 +1,8 @@
+mod components;
+mod effect;
+pub mod messages;
+mod plugin;
+mod resources;
+mod systems;
+
+pub use plugin::ParticlesPlugin;
@@ -0,0 +1,38 @@
+use super::effect::{ParticleEffect, ParticleEffectLoader};
+use super::messages::SpawnParticles;
+use super::resources::ParticleRng;
+use super::systems::{
+    attach_run_trail, load_particle_effects, spawn_coin_sparkle, spawn_enemy_poof, spawn_particles,
+    spawn_player_dust, spawn_splash, start_run_trail, stop_run_trail, tick_particle_emitters,
+    update_particles,
+};
+use bevy::prelude::*;
+
+pub struct ParticlesPlugin;
+
+impl Plugin for ParticlesPlugin {
+    fn build(&self, app: &mut App) {
+        app.init_asset::<ParticleEffect>()
+            .init_asset_loader::<ParticleEffectLoader>()
+            .init_resource::<ParticleRng>()
+            .add_message::<SpawnParticles>()
+            .add_systems(Startup, load_particle_effects)
+            .add_systems(
+                Update,
+                (
+                    (
+                        (attach_run_trail, tick_particle_emitters).chain(),
+                        spawn_player_dust,
+                        spawn_coin_sparkle,
+                        spawn_enemy_poof,
+                        spawn_splash,
+                    ),
+                    spawn_particles,
+                    update_particles,
+                )
+                    .chain(),
+            )
+            .add_observer(start_run_trail)
+            .add_observer(stop_run_trail);
+    }
+}
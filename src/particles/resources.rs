@@ -0,0 +1,34 @@
+use super::effect::ParticleEffect;
+use bevy::prelude::*;
+
+/// Handles to the effects spawned by gameplay messages.
+#[derive(Resource)]
+pub struct ParticleEffects {
+    pub land_dust: Handle<ParticleEffect>,
+    pub jump_dust: Handle<ParticleEffect>,
+    pub run_trail: Handle<ParticleEffect>,
+    pub coin_sparkle: Handle<ParticleEffect>,
+    pub enemy_poof: Handle<ParticleEffect>,
+    pub splash: Handle<ParticleEffect>,
+}
+
+/// Small xorshift generator for particle variation. Not for anything gameplay-relevant.
+#[derive(Resource)]
+pub struct ParticleRng(u32);
+
+impl Default for ParticleRng {
+    fn default() -> Self {
+        Self(0x9E37_79B9)
+    }
+}
+
+impl ParticleRng {
+    /// Uniform value in `min..=max`.
+    pub fn range(&mut self, min: f32, max: f32) -> f32 {
+        self.0 ^= self.0 << 13;
+        self.0 ^= self.0 >> 17;
+        self.0 ^= self.0 << 5;
+        let unit = (self.0 >> 8) as f32 / (1 << 24) as f32;
+        min + (max - min) * unit
+    }
+}
@@ -0,0 +1,261 @@
+use super::components::{Particle, ParticleEmitter};
+use super::effect::ParticleEffect;
+use super::messages::SpawnParticles;
+use super::resources::{ParticleEffects, ParticleRng};
+use crate::enemy::messages::EnemyDefeated;
+use crate::pickups::components::PickupKind;
+use crate::pickups::messages::PickupCollected;
+use crate::player::{
+    EnterPlayerState, ExitPlayerState, PLAYER_HALF_HEIGHT, Player, PlayerState, PlayerStateChanged,
+    PlayerStateGroup,
+};
+use crate::state::GameState;
+use crate::water::messages::Splash;
+use bevy::prelude::*;
+
+/// Draw depth of particles, in front of the level and characters
+const PARTICLE_Z: f32 = 6.0;
+
+pub fn load_particle_effects(mut commands: Commands, asset_server: Res<AssetServer>) {
+    commands.insert_resource(ParticleEffects {
+        land_dust: asset_server.load("particles/land_dust.particle.ron"),
+        jump_dust: asset_server.load("particles/jump_dust.particle.ron"),
+        run_trail: asset_server.load("particles/run_trail.particle.ron"),
+        coin_sparkle: asset_server.load("particles/coin_sparkle.particle.ron"),
+        enemy_poof: asset_server.load("particles/enemy_poof.particle.ron"),
+        splash: asset_server.load("particles/splash.particle.ron"),
+    });
+}
+
+/// Gives new players a run trail emitter, switched on by `start_run_trail`.
+pub fn attach_run_trail(
+    mut commands: Commands,
+    effects: Res<ParticleEffects>,
+    players: Query<Entity, Added<Player>>,
+) {
+    for player in &players {
+        commands.entity(player).insert(ParticleEmitter::new(
+            effects.run_trail.clone(),
+            Vec2::new(0.0, -PLAYER_HALF_HEIGHT),
+        ));
+    }
+}
+
+/// Starts the trail as the player enters the Run state.
+pub fn start_run_trail(
+    enter: On<EnterPlayerState>,
+    mut emitters: Query<&mut ParticleEmitter, With<Player>>,
+) {
+    if enter.state == PlayerState::Run
+        && let Ok(mut emitter) = emitters.get_mut(enter.entity)
+    {
+        emitter.active = true;
+    }
+}
+
+/// Stops the trail as the player leaves the Run state.
+pub fn stop_run_trail(
+    exit: On<ExitPlayerState>,
+    mut emitters: Query<&mut ParticleEmitter, With<Player>>,
+) {
+    if exit.state == PlayerState::Run
+        && let Ok(mut emitter) = emitters.get_mut(exit.entity)
+    {
+        emitter.active = false;
+    }
+}
+
+/// Turns player landings and jumps into dust puffs at their feet.
+pub fn spawn_player_dust(
+    mut messages: MessageReader<PlayerStateChanged>,
+    effects: Res<ParticleEffects>,
+    players: Query<&GlobalTransform, With<Player>>,
+    mut spawns: MessageWriter<SpawnParticles>,
+) {
+    for evt in messages.read() {
+        let from = evt.from.group();
+        let to = evt.to.group();
+        let effect = if from == PlayerStateGroup::Airborne && to == PlayerStateGroup::Grounded {
+            &effects.land_dust
+        } else if matches!(evt.to, PlayerState::Jump | PlayerState::Flip) {
+            &effects.jump_dust
+        } else {
+            continue;
+        };
+
+        let Ok(transform) = players.get(evt.player) else {
+            continue;
+        };
+        spawns.write(SpawnParticles {
+            effect: effect.clone(),
+            position: transform.translation().truncate() - Vec2::Y * PLAYER_HALF_HEIGHT,
+            mirror: false,
+        });
+    }
+}
+
+/// Sparkles where coins were collected.
+pub fn spawn_coin_sparkle(
+    mut messages: MessageReader<PickupCollected>,
+    effects: Res<ParticleEffects>,
+    mut spawns: MessageWriter<SpawnParticles>,
+) {
+    for evt in messages.read() {
+        if evt.kind == PickupKind::Coin {
+            spawns.write(SpawnParticles {
+                effect: effects.coin_sparkle.clone(),
+                position: evt.position,
+                mirror: false,
+            });
+        }
+    }
+}
+
+/// Puffs of smoke where enemies were defeated.
+pub fn spawn_enemy_poof(
+    mut messages: MessageReader<EnemyDefeated>,
+    effects: Res<ParticleEffects>,
+    mut spawns: MessageWriter<SpawnParticles>,
+) {
+    for evt in messages.read() {
+        spawns.write(SpawnParticles {
+            effect: effects.enemy_poof.clone(),
+            position: evt.position,
+            mirror: false,
+        });
+    }
+}
+
+/// Throws up droplets where the player hits the water.
+pub fn spawn_splash(
+    mut messages: MessageReader<Splash>,
+    effects: Res<ParticleEffects>,
+    mut spawns: MessageWriter<SpawnParticles>,
+) {
+    for evt in messages.read() {
+        spawns.write(SpawnParticles {
+            effect: effects.splash.clone(),
+            position: evt.position,
+            mirror: false,
+        });
+    }
+}
+
+/// Emits a burst from each active emitter every `interval` seconds.
+/// Bursts are mirrored when the emitter's sprite is flipped, so trails stay behind it.
+pub fn tick_particle_emitters(
+    time: Res<Time>,
+    effects: Res<Assets<ParticleEffect>>,
+    mut emitters: Query<(&mut ParticleEmitter, &GlobalTransform, Option<&Sprite>)>,
+    mut spawns: MessageWriter<SpawnParticles>,
+) {
+    for (mut emitter, transform, sprite) in &mut emitters {
+        if !emitter.active {
+            emitter.elapsed = 0.0;
+            continue;
+        }
+        let Some(effect) = effects.get(&emitter.effect) else {
+            continue;
+        };
+
+        emitter.elapsed += time.delta_secs();
+        if emitter.elapsed < effect.interval {
+            continue;
+        }
+        emitter.elapsed = 0.0;
+
+        spawns.write(SpawnParticles {
+            effect: emitter.effect.clone(),
+            position: transform.translation().truncate() + emitter.offset,
+            mirror: sprite.is_some_and(|sprite| sprite.flip_x),
+        });
+    }
+}
+
+/// Spawns the particles of each requested burst. Bursts of effects still loading are dropped.
+pub fn spawn_particles(
+    mut commands: Commands,
+    mut messages: MessageReader<SpawnParticles>,
+    effects: Res<Assets<ParticleEffect>>,
+    mut rng: ResMut<ParticleRng>,
+) {
+    for evt in messages.read() {
+        let Some(effect) = effects.get(&evt.effect) else {
+            continue;
+        };
+
+        let direction = if evt.mirror {
+            180.0 - effect.direction
+        } else {
+            effect.direction
+        };
+        let half_spread = effect.spread * 0.5;
+
+        for _ in 0..effect.count {
+            let angle = rng.range(direction - half_spread, direction + half_spread);
+            let speed = rng.range(effect.speed.0, effect.speed.1);
+            let offset = Vec2::new(
+                rng.range(-effect.area.0, effect.area.0),
+                rng.range(-effect.area.1, effect.area.1),
+            );
+            let position = evt.position + offset;
+
+            let mut sprite = Sprite::from_color(effect.color_at(0.0), Vec2::splat(effect.size));
+            if let (Some(image), Some(layout), Some(index)) =
+                (&effect.image, &effect.layout, effect.frame_at(0.0))
+            {
+                sprite.image = image.clone();
+                sprite.texture_atlas = Some(TextureAtlas {
+                    layout: layout.clone(),
+                    index,
+                });
+            }
+
+            commands.spawn((
+                Name::new("Particle"),
+                Particle {
+                    effect: evt.effect.id(),
+                    velocity: Vec2::from_angle(angle.to_radians()) * speed,
+                    age: 0.0,
+                    lifetime: rng.range(effect.lifetime.0, effect.lifetime.1),
+                },
+                sprite,
+                Transform::from_xyz(position.x, position.y, PARTICLE_Z),
+                DespawnOnExit(GameState::Playing),
+            ));
+        }
+    }
+}
+
+/// Moves particles under their effect's gravity and drag, stepping color and atlas frame
+/// through their life, and despawns them once it is over.
+pub fn update_particles(
+    mut commands: Commands,
+    time: Res<Time>,
+    effects: Res<Assets<ParticleEffect>>,
+    mut particles: Query<(Entity, &mut Particle, &mut Transform, &mut Sprite)>,
+) {
+    let dt = time.delta_secs();
+
+    for (entity, mut particle, mut transform, mut sprite) in &mut particles {
+        particle.age += dt;
+        let Some(effect) = effects.get(particle.effect) else {
+            commands.entity(entity).despawn();
+            continue;
+        };
+        if particle.age >= particle.lifetime {
+            commands.entity(entity).despawn();
+            continue;
+        }
+
+        particle.velocity.y += effect.gravity * dt;
+        particle.velocity *= (1.0 - effect.drag * dt).max(0.0);
+        transform.translation += (particle.velocity * dt).extend(0.0);
+
+        let t = particle.age / particle.lifetime;
+        sprite.color = effect.color_at(t);
+        if let (Some(atlas), Some(index)) = (&mut sprite.texture_atlas, effect.frame_at(t)) {
+            atlas.index = index;
+        }
+    }
+}
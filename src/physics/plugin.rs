@@ -4,6 +4,7 @@ use bevy_ecs_tiled::prelude::*;
 
 use super::systems::make_colliders_static;
 use crate::platform::PlatformHooks;
+use crate::player::PlayerTuning;
 
 pub struct PhysicsPlugin;
 
@@ -15,7 +16,8 @@ impl Plugin for PhysicsPlugin {
                 .with_collision_hooks::<PlatformHooks>(),
         )
         .add_plugins(TiledPhysicsPlugin::<TiledPhysicsAvianBackend>::default())
-        .insert_resource(Gravity(Vec2::new(0.0, -980.0)))
+        // Replaced by the tuning asset once it loads
+        .insert_resource(Gravity(Vec2::new(0.0, PlayerTuning::default().gravity)))
         .add_observer(make_colliders_static);
     }
 }
@@ -1,6 +1,71 @@
 use bevy::prelude::*;
 
+/// What kind of pickup this is, deciding which systems react to its collection.
+#[derive(Reflect, Default, Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum PickupKind {
+    /// Adds `value` to the score.
+    #[default]
+    Coin,
+    /// Applies the entity's `PowerUp` effect.
+    PowerUp,
+    /// Adds the entity's `Key` to the player's key ring.
+    Key,
+    /// Unlocks the entity's `AbilityUnlock` ability.
+    Ability,
+}
+
+/// Anything the player can collect by touching it.
+#[derive(Component, Reflect, Debug, Clone)]
+#[reflect(Component, Default)]
+pub struct Pickup {
+    pub kind: PickupKind,
+    /// Score worth for coins (e.g. 5 for a gem)
+    pub value: u32,
+    /// Seconds until the pickup reappears after collection. 0 = never.
+    pub respawn_time: f32,
+}
+
+impl Default for Pickup {
+    fn default() -> Self {
+        Self {
+            kind: PickupKind::Coin,
+            value: 1,
+            respawn_time: 0.0,
+        }
+    }
+}
+
 /// Marker component for collectible coins.
 #[derive(Component, Debug, Clone, Default, Reflect)]
 #[reflect(Component, Default)]
+#[require(Pickup)]
 pub struct Coin;
+
+/// Pickup was collected this frame and is hidden or despawned at the end of it.
+#[derive(Component, Default)]
+pub struct Collected;
+
+/// Counts down until a collected pickup reappears.
+#[derive(Component, Reflect)]
+#[reflect(Component)]
+pub struct PickupRespawn(pub Timer);
+
+/// Stable identity of a pickup placed in Tiled: its map and object id.
+#[derive(Component, Reflect, Debug, Clone, PartialEq, Eq, Hash)]
+#[reflect(Component)]
+pub struct PickupId {
+    /// Asset path of the map
+    pub map: String,
+    /// Tiled object id
+    pub object: u32,
+}
+
+/// Coin that was collected in an earlier session, drawn translucent.
+/// Collecting it again is worth no score.
+#[derive(Component, Default)]
+pub struct PreviouslyCollected;
+
+/// Marker for the level completion HUD text.
+#[derive(Component, Reflect, Default)]
+#[reflect(Component)]
+pub struct LevelSummaryText;
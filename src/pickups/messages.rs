@@ -1,5 +1,13 @@
+use super::components::PickupKind;
 use bevy::prelude::*;
 
-/// Sent when a coin is collected.
-#[derive(Message)]
-pub struct CoinCollected;
+/// Sent when the player collects any pickup.
+#[derive(Message, Debug, Clone)]
+pub struct PickupCollected {
+    pub kind: PickupKind,
+    pub value: u32,
+    /// World position of the pickup
+    pub position: Vec2,
+    /// The pickup entity, alive until the end of the frame
+    pub entity: Entity,
+}
@@ -1,6 +1,7 @@
-mod components;
+pub mod components;
 pub mod messages;
 mod plugin;
+pub mod resources;
 mod systems;
 
-pub use plugin::PickupsPlugin;
+pub use plugin::{PickupSystemSet, PickupsPlugin};
@@ -1,14 +1,53 @@
-use super::components::Coin;
-use super::messages::CoinCollected;
-use super::systems::{collect_coins, setup_coin_sensors};
+use super::components::{Coin, LevelSummaryText, Pickup, PickupId, PickupRespawn};
+use super::messages::PickupCollected;
+use super::resources::{CollectedPickups, LevelSummary, PickupTracking};
+use super::systems::{
+    assign_pickup_ids, collect_pickups, fade_previously_collected, finish_collected_pickups,
+    forget_pickups_on_death, record_collected_pickups, respawn_pickups, setup_pickup_sensors,
+    spawn_level_summary_hud, update_level_summary_hud,
+};
 use bevy::prelude::*;
 
+/// Systems reacting to `PickupCollected` should run after `Collect`, while
+/// the collected entity is still alive.
+#[derive(SystemSet, Debug, Clone, PartialEq, Eq, Hash)]
+pub enum PickupSystemSet {
+    Collect,
+}
+
 pub struct PickupsPlugin;
 
 impl Plugin for PickupsPlugin {
     fn build(&self, app: &mut App) {
-        app.register_type::<Coin>()
-            .add_message::<CoinCollected>()
-            .add_systems(Update, (setup_coin_sensors, collect_coins));
+        app.register_type::<Pickup>()
+            .register_type::<Coin>()
+            .register_type::<PickupRespawn>()
+            .register_type::<PickupId>()
+            .register_type::<LevelSummaryText>()
+            .register_type::<CollectedPickups>()
+            .register_type::<PickupTracking>()
+            .register_type::<LevelSummary>()
+            .init_resource::<CollectedPickups>()
+            .init_resource::<PickupTracking>()
+            .init_resource::<LevelSummary>()
+            .add_message::<PickupCollected>()
+            .add_systems(Startup, spawn_level_summary_hud)
+            .add_systems(
+                Update,
+                (setup_pickup_sensors, collect_pickups).in_set(PickupSystemSet::Collect),
+            )
+            .add_systems(
+                Update,
+                (
+                    (assign_pickup_ids, fade_previously_collected).chain(),
+                    record_collected_pickups.after(PickupSystemSet::Collect),
+                    forget_pickups_on_death,
+                    respawn_pickups,
+                    update_level_summary_hud.run_if(
+                        resource_changed::<LevelSummary>.or(resource_changed::<CollectedPickups>),
+                    ),
+                ),
+            )
+            .add_systems(PostUpdate, finish_collected_pickups);
     }
 }
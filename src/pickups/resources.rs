@@ -0,0 +1,60 @@
+use super::components::PickupId;
+use bevy::{
+    platform::collections::{HashMap, HashSet},
+    prelude::*,
+};
+
+/// Tiled object ids of coins collected so far, keyed by map asset path.
+#[derive(Resource, Reflect, Default)]
+#[reflect(Resource)]
+pub struct CollectedPickups {
+    pub maps: HashMap<String, HashSet<u32>>,
+    /// Coins loaded from the save file, collected in an earlier session.
+    pub previous_sessions: HashMap<String, HashSet<u32>>,
+    /// Coins collected since the player last spawned.
+    pub this_life: Vec<PickupId>,
+}
+
+impl CollectedPickups {
+    /// Whether the coin was collected in an earlier session.
+    pub fn collected_before(&self, id: &PickupId) -> bool {
+        self.previous_sessions
+            .get(&id.map)
+            .is_some_and(|ids| ids.contains(&id.object))
+    }
+}
+
+/// Controls how collected coins are remembered.
+#[derive(Resource, Reflect)]
+#[reflect(Resource)]
+pub struct PickupTracking {
+    /// Keep coins collected before dying. When false, they return on respawn.
+    pub keep_on_death: bool,
+}
+
+impl Default for PickupTracking {
+    fn default() -> Self {
+        Self {
+            keep_on_death: true,
+        }
+    }
+}
+
+/// Coin object ids and values placed in the current map.
+#[derive(Resource, Reflect, Default)]
+#[reflect(Resource)]
+pub struct LevelSummary {
+    pub map: String,
+    pub coins: HashMap<u32, u32>,
+}
+
+impl LevelSummary {
+    /// Returns (collected value, total value) of this map's coins.
+    pub fn progress(&self, collected: &CollectedPickups) -> (u32, u32) {
+        let recorded = collected.maps.get(&self.map);
+        self.coins.iter().fold((0, 0), |(got, total), (id, value)| {
+            let is_collected = recorded.is_some_and(|ids| ids.contains(id));
+            (got + if is_collected { *value } else { 0 }, total + value)
+        })
+    }
+}
@@ -1,18 +1,22 @@
-use super::components::Coin;
-use super::messages::CoinCollected;
+use super::components::{
+    Collected, LevelSummaryText, Pickup, PickupId, PickupKind, PickupRespawn, PreviouslyCollected,
+};
+use super::messages::PickupCollected;
+use super::resources::{CollectedPickups, LevelSummary, PickupTracking};
+use crate::killzone::components::DeathTimer;
 use crate::player::Player;
 use avian2d::prelude::*;
 use bevy::prelude::*;
 use bevy_ecs_tiled::prelude::*;
 
-/// Makes coin colliders into sensors when they're created by bevy_ecs_tiled.
-pub fn setup_coin_sensors(
+/// Makes pickup colliders into sensors when they're created by bevy_ecs_tiled.
+pub fn setup_pickup_sensors(
     mut collider_events: MessageReader<TiledEvent<ColliderCreated>>,
-    coins: Query<(), With<Coin>>,
+    pickups: Query<(), With<Pickup>>,
     mut commands: Commands,
 ) {
     for evt in collider_events.read() {
-        if coins.get(*evt.event.collider_of).is_ok() {
+        if pickups.get(*evt.event.collider_of).is_ok() {
             commands
                 .entity(evt.origin)
                 .insert((Sensor, CollisionEventsEnabled));
@@ -20,40 +24,231 @@ pub fn setup_coin_sensors(
     }
 }
 
-/// Collects coins when the player touches them.
-pub fn collect_coins(
+/// Collects pickups when the player touches them.
+pub fn collect_pickups(
     mut collision_events: MessageReader<CollisionStart>,
     mut commands: Commands,
-    mut coin_events: MessageWriter<CoinCollected>,
-    coins: Query<(), With<Coin>>,
+    mut pickup_events: MessageWriter<PickupCollected>,
+    pickups: Query<(&Pickup, &GlobalTransform, Has<PreviouslyCollected>), Without<Collected>>,
     players: Query<(), With<Player>>,
     collider_query: Query<&TiledColliderOf>,
 ) {
     for evt in collision_events.read() {
-        // Check both colliders - coin could be either one depending on collision direction
-        let (coin_entity, player_body) = match (
+        // Check both colliders - pickup could be either one depending on collision direction
+        let (pickup_entity, player_body) = match (
             collider_query
                 .get(evt.collider1)
                 .ok()
-                .filter(|c| coins.contains(c.0)),
+                .filter(|c| pickups.contains(c.0)),
             collider_query
                 .get(evt.collider2)
                 .ok()
-                .filter(|c| coins.contains(c.0)),
+                .filter(|c| pickups.contains(c.0)),
         ) {
-            (Some(coin), None) => (Some(coin.0), evt.body2),
-            (None, Some(coin)) => (Some(coin.0), evt.body1),
+            (Some(pickup), None) => (Some(pickup.0), evt.body2),
+            (None, Some(pickup)) => (Some(pickup.0), evt.body1),
             _ => (None, None),
         };
 
         // Check if the other body is a player
         let is_player = player_body.is_some_and(|body| players.contains(body));
 
-        if let Some(coin) = coin_entity
+        if let Some(entity) = pickup_entity
             && is_player
+            && let Ok((pickup, transform, ghost)) = pickups.get(entity)
         {
-            coin_events.write(CoinCollected);
-            commands.entity(coin).despawn();
+            pickup_events.write(PickupCollected {
+                kind: pickup.kind,
+                // Ghost coins were already scored in the session that collected them
+                value: if ghost { 0 } else { pickup.value },
+                position: transform.translation().truncate(),
+                entity,
+            });
+            commands.entity(entity).insert(Collected);
         }
     }
 }
+
+/// Despawns collected pickups, or hides them until their respawn time passes.
+pub fn finish_collected_pickups(
+    mut commands: Commands,
+    pickups: Query<(Entity, &Pickup), Added<Collected>>,
+    colliders: Query<(Entity, &TiledColliderOf)>,
+) {
+    for (entity, pickup) in &pickups {
+        if pickup.respawn_time <= 0.0 {
+            commands.entity(entity).despawn();
+            continue;
+        }
+
+        commands.entity(entity).insert((
+            Visibility::Hidden,
+            PickupRespawn(Timer::from_seconds(pickup.respawn_time, TimerMode::Once)),
+        ));
+        for (collider, _) in colliders.iter().filter(|(_, c)| c.0 == entity) {
+            commands.entity(collider).insert(ColliderDisabled);
+        }
+    }
+}
+
+/// Brings hidden pickups back once their respawn timer expires.
+pub fn respawn_pickups(
+    mut commands: Commands,
+    time: Res<Time>,
+    mut pickups: Query<(Entity, &mut PickupRespawn)>,
+    colliders: Query<(Entity, &TiledColliderOf)>,
+) {
+    for (entity, mut respawn) in &mut pickups {
+        respawn.0.tick(time.delta());
+        if !respawn.0.just_finished() {
+            continue;
+        }
+
+        commands
+            .entity(entity)
+            .remove::<(Collected, PickupRespawn)>()
+            .insert(Visibility::Inherited);
+        for (collider, _) in colliders.iter().filter(|(_, c)| c.0 == entity) {
+            commands.entity(collider).remove::<ColliderDisabled>();
+        }
+    }
+}
+
+/// Tags Tiled pickups with their map and object id, and marks coins that were
+/// already collected in an earlier session.
+pub fn assign_pickup_ids(
+    mut commands: Commands,
+    pickups: Query<(Entity, &Pickup, &TiledMapReference), Without<PickupId>>,
+    maps: Query<(&TiledMap, &TiledMapStorage)>,
+    collected: Res<CollectedPickups>,
+    mut summary: ResMut<LevelSummary>,
+) {
+    for (entity, pickup, map_ref) in &pickups {
+        let Ok((tiled_map, storage)) = maps.get(map_ref.0) else {
+            continue;
+        };
+        let (Some(path), Some(object)) = (tiled_map.0.path(), storage.get_object_id(entity)) else {
+            continue;
+        };
+
+        let id = PickupId {
+            map: path.to_string(),
+            object,
+        };
+
+        if pickup.kind == PickupKind::Coin {
+            if summary.map != id.map {
+                summary.map = id.map.clone();
+                summary.coins.clear();
+            }
+            summary.coins.insert(id.object, pickup.value);
+
+            if collected.collected_before(&id) {
+                commands.entity(entity).insert(PreviouslyCollected);
+            }
+        }
+
+        commands.entity(entity).insert(id);
+    }
+}
+
+/// Draws previously collected coins as translucent ghosts.
+pub fn fade_previously_collected(
+    pickups: Query<Entity, Added<PreviouslyCollected>>,
+    children: Query<&Children>,
+    mut sprites: Query<&mut Sprite>,
+) {
+    for entity in &pickups {
+        for sprite_entity in std::iter::once(entity).chain(children.iter_descendants(entity)) {
+            if let Ok(mut sprite) = sprites.get_mut(sprite_entity) {
+                sprite.color.set_alpha(0.35);
+            }
+        }
+    }
+}
+
+/// Records collected coins by their Tiled object id.
+pub fn record_collected_pickups(
+    mut messages: MessageReader<PickupCollected>,
+    ids: Query<&PickupId>,
+    mut collected: ResMut<CollectedPickups>,
+) {
+    for evt in messages.read() {
+        if evt.kind != PickupKind::Coin {
+            continue;
+        }
+        let Ok(id) = ids.get(evt.entity) else {
+            continue;
+        };
+
+        if collected
+            .maps
+            .entry(id.map.clone())
+            .or_default()
+            .insert(id.object)
+        {
+            collected.this_life.push(id.clone());
+        }
+    }
+}
+
+/// Forgets coins collected during the last life unless tracking keeps them.
+pub fn forget_pickups_on_death(
+    players: Query<(), (With<Player>, Added<DeathTimer>)>,
+    tracking: Res<PickupTracking>,
+    mut collected: ResMut<CollectedPickups>,
+) {
+    if players.is_empty() {
+        return;
+    }
+
+    let lost = std::mem::take(&mut collected.this_life);
+    if tracking.keep_on_death {
+        return;
+    }
+
+    for id in lost {
+        if let Some(ids) = collected.maps.get_mut(&id.map) {
+            ids.remove(&id.object);
+        }
+    }
+}
+
+/// Spawns the HUD line showing coin progress for the level.
+pub fn spawn_level_summary_hud(mut commands: Commands, asset_server: Res<AssetServer>) {
+    commands.spawn((
+        Name::new("Level Summary HUD"),
+        LevelSummaryText,
+        Text::default(),
+        TextFont {
+            font: asset_server.load("fonts/PixelOperator8.ttf"),
+            font_size: 16.0,
+            ..default()
+        },
+        TextColor(Color::WHITE),
+        Node {
+            position_type: PositionType::Absolute,
+            top: Val::Px(12.0),
+            left: Val::Px(12.0),
+            ..default()
+        },
+    ));
+}
+
+/// Shows "collected/total" coins, flagging 100% completion.
+pub fn update_level_summary_hud(
+    summary: Res<LevelSummary>,
+    collected: Res<CollectedPickups>,
+    mut hud: Query<&mut Text, With<LevelSummaryText>>,
+) {
+    let (got, total) = summary.progress(&collected);
+    let content = if total > 0 && got == total {
+        format!("Coins {got}/{total} - 100%!")
+    } else {
+        format!("Coins {got}/{total}")
+    };
+
+    for mut text in &mut hud {
+        text.0 = content.clone();
+    }
+}
@@ -5,21 +5,30 @@ use bevy::{
     prelude::*,
 };
 
-fn setup_platform_sprite(mut world: DeferredWorld, ctx: HookContext) {
-    let entity = ctx.entity;
-
+/// Sets the sprite to the 32x9 platform in `row` of platforms.png.
+fn apply_platform_sprite(world: &mut DeferredWorld, entity: Entity, row: u32) {
     let texture = world
         .get_resource::<PlatformTexture>()
         .expect("PlatformTexture resource must be present")
         .texture
         .clone();
 
+    let top = row as f32 * 16.0;
     if let Some(mut sprite) = world.get_mut::<Sprite>(entity) {
         sprite.image = texture;
-        sprite.rect = Some(Rect::new(16.0, 0.0, 48.0, 9.0));
+        sprite.rect = Some(Rect::new(16.0, top, 48.0, top + 9.0));
     }
 }
 
+fn setup_platform_sprite(mut world: DeferredWorld, ctx: HookContext) {
+    apply_platform_sprite(&mut world, ctx.entity, 0);
+}
+
+fn setup_crumbling_platform_sprite(mut world: DeferredWorld, ctx: HookContext) {
+    // Brown row, so crumbling platforms read differently from solid ones
+    apply_platform_sprite(&mut world, ctx.entity, 1);
+}
+
 #[derive(Component, Default, Reflect)]
 #[reflect(Component)]
 #[require(
@@ -31,8 +40,58 @@ fn setup_platform_sprite(mut world: DeferredWorld, ctx: HookContext) {
 #[component(on_add = setup_platform_sprite)]
 pub struct OneWayPlatform;
 
-/// Tiled polyline for a moving platform.
-/// First point = left edge at start, last point = right edge at end.
+/// How a moving platform continues after reaching the end of its path.
+#[derive(Reflect, Default, Debug, Clone, Copy, PartialEq, Eq)]
+pub enum PathMode {
+    /// Travel back along the path in reverse.
+    #[default]
+    PingPong,
+    /// Travel from the last vertex straight back to the first and repeat.
+    Loop,
+    /// Stop at the last vertex.
+    OneShot,
+}
+
+/// What sets a moving platform in motion.
+#[derive(Reflect, Default, Debug, Clone, Copy, PartialEq, Eq)]
+pub enum PlatformTrigger {
+    /// Moves from the start (while powered, if linked from a switch).
+    #[default]
+    Always,
+    /// Waits at each end of its path until the player stands on it.
+    OnStand,
+    /// Waits at each end of its path until a linked switch turns on.
+    OnSwitch,
+}
+
+/// Speed curve applied when approaching or leaving a waypoint where the platform stops.
+#[derive(Reflect, Default, Debug, Clone, Copy, PartialEq, Eq)]
+pub enum PlatformEasing {
+    #[default]
+    Linear,
+    Sine,
+    Quadratic,
+}
+
+impl PlatformEasing {
+    /// Maps progress through the easing distance (0..1) to a speed factor (0..1).
+    pub fn apply(self, t: f32) -> f32 {
+        let t = t.clamp(0.0, 1.0);
+        match self {
+            Self::Linear => 1.0,
+            Self::Sine => (t * std::f32::consts::FRAC_PI_2).sin(),
+            Self::Quadratic => 1.0 - (1.0 - t) * (1.0 - t),
+        }
+    }
+}
+
+/// Tiled polyline or polygon for a moving platform.
+/// Each vertex is the top-center of the platform at that waypoint.
+///
+/// Paths used to be drawn from the platform's left edge at the first vertex to its right
+/// edge at the last. Maps drawn that way need the first vertex moved right and the last
+/// moved left by half the platform width (16 pixels), or the platform travels a platform
+/// width too far.
 #[derive(Component, Debug, Clone, Reflect)]
 #[reflect(Component, Default)]
 #[require(
@@ -46,24 +105,253 @@ pub struct OneWayPlatform;
 pub struct MovingPlatform {
     /// Speed in pixels per second
     pub speed: f32,
-    /// Start position (center) - computed from polyline
+    pub mode: PathMode,
+    /// Seconds to wait at every waypoint
+    pub wait_time: f32,
+    /// Comma-separated per-waypoint wait times, overriding `wait_time` (e.g. "1,0,0,2")
+    pub wait_times: String,
+    pub easing: PlatformEasing,
+    /// Distance in pixels over which the platform eases in/out of a stop
+    pub ease_distance: f32,
+    /// Fraction (0..1) of the path the platform starts at, to phase-shift platforms sharing a path
+    pub start_offset: f32,
+    pub trigger: PlatformTrigger,
+    /// Seconds a triggered platform waits at the end before returning on its own. 0 = stay.
+    pub return_delay: f32,
+    /// Stop instead of crushing a player or enemy against solid geometry
+    pub safe: bool,
+    /// Kill enemies squeezed between this platform and solid geometry
+    pub crush_enemies: bool,
+    /// Waypoint centers - computed from the Tiled shape
+    #[reflect(ignore)]
+    pub waypoints: Vec<Vec2>,
+    /// Per-waypoint wait in seconds - computed from `wait_time`/`wait_times`
+    #[reflect(ignore)]
+    pub waits: Vec<f32>,
+    /// Index of the waypoint being travelled to
     #[reflect(ignore)]
-    pub start: Vec2,
-    /// End position (center) - computed from polyline
+    pub target: usize,
+    /// Index of the waypoint last departed from
     #[reflect(ignore)]
-    pub end: Vec2,
-    /// 1.0 = toward end, -1.0 = toward start
+    pub previous: usize,
+    /// 1 = toward later waypoints, -1 = toward earlier ones
     #[reflect(ignore)]
-    pub direction: f32,
+    pub direction: isize,
+    /// Seconds left to wait at the current waypoint
+    #[reflect(ignore)]
+    pub wait_remaining: f32,
+    /// Set once a OneShot platform reaches its last waypoint
+    #[reflect(ignore)]
+    pub finished: bool,
+    /// Triggered platform is resting until its trigger fires
+    #[reflect(ignore)]
+    pub awaiting_trigger: bool,
+    /// Player was standing on the platform last tick
+    #[reflect(ignore)]
+    pub ridden: bool,
 }
 
+/// A safe platform is wedging something against solid geometry and may not move
+/// along this direction (from the platform toward the squeezed body).
+#[derive(Component, Debug, Clone, Copy)]
+pub struct CrushBlocked(pub Vec2);
+
 impl Default for MovingPlatform {
     fn default() -> Self {
         Self {
             speed: 50.0,
-            start: Vec2::ZERO,
-            end: Vec2::ZERO,
-            direction: 1.0,
+            mode: PathMode::PingPong,
+            wait_time: 0.0,
+            wait_times: String::new(),
+            easing: PlatformEasing::Linear,
+            ease_distance: 16.0,
+            start_offset: 0.0,
+            trigger: PlatformTrigger::Always,
+            return_delay: 0.0,
+            safe: false,
+            crush_enemies: false,
+            waypoints: Vec::new(),
+            waits: Vec::new(),
+            target: 0,
+            previous: 0,
+            direction: 1,
+            wait_remaining: 0.0,
+            finished: false,
+            awaiting_trigger: false,
+            ridden: false,
+        }
+    }
+}
+
+impl MovingPlatform {
+    /// Whether the platform comes to a halt at waypoint `index`.
+    pub fn stops_at(&self, index: usize) -> bool {
+        let last = self.waypoints.len().saturating_sub(1);
+        self.waits.get(index).is_some_and(|w| *w > 0.0)
+            || self.rests_at(index)
+            || match self.mode {
+                PathMode::PingPong => index == 0 || index == last,
+                PathMode::Loop => false,
+                PathMode::OneShot => index == last,
+            }
+    }
+
+    /// Whether a triggered platform comes to rest at waypoint `index` until triggered again.
+    pub fn rests_at(&self, index: usize) -> bool {
+        let last = self.waypoints.len().saturating_sub(1);
+        self.trigger != PlatformTrigger::Always
+            && match self.mode {
+                PathMode::PingPong | PathMode::OneShot => index == 0 || index == last,
+                PathMode::Loop => index == 0,
+            }
+    }
+
+    /// Index of the waypoint after `target`, updating `direction` for ping-pong.
+    /// Returns None when a one-shot path is complete.
+    pub fn next_waypoint(&mut self) -> Option<usize> {
+        let len = self.waypoints.len();
+        match self.mode {
+            PathMode::PingPong => {
+                let next = self.target as isize + self.direction;
+                if next < 0 || next >= len as isize {
+                    self.direction = -self.direction;
+                }
+                Some((self.target as isize + self.direction) as usize)
+            }
+            PathMode::Loop => Some((self.target + 1) % len),
+            PathMode::OneShot => (self.target + 1 < len).then_some(self.target + 1),
         }
     }
+
+    /// Waypoint index pairs travelled in one pass over the path.
+    fn segments(&self) -> impl Iterator<Item = (usize, usize)> + '_ {
+        let len = self.waypoints.len();
+        let count = if self.mode == PathMode::Loop {
+            len
+        } else {
+            len.saturating_sub(1)
+        };
+        (0..count).map(move |i| (i, (i + 1) % len))
+    }
+
+    /// Moves the platform state to `fraction` of the way along its path.
+    /// Returns the position at that point.
+    pub fn seek(&mut self, fraction: f32) -> Vec2 {
+        let total: f32 = self
+            .segments()
+            .map(|(a, b)| self.waypoints[a].distance(self.waypoints[b]))
+            .sum();
+        let mut remaining = total * fraction.rem_euclid(1.0);
+
+        let segments: Vec<_> = self.segments().collect();
+        for (a, b) in segments {
+            let (from, to) = (self.waypoints[a], self.waypoints[b]);
+            let length = from.distance(to);
+            if remaining < length {
+                self.previous = a;
+                self.target = b;
+                self.direction = 1;
+                return from.lerp(to, remaining / length);
+            }
+            remaining -= length;
+        }
+
+        self.previous = 0;
+        self.target = 1.min(self.waypoints.len() - 1);
+        self.waypoints[0]
+    }
+}
+
+/// Platform that shakes after the player lands on it, falls, and comes back later.
+/// Place as a Tiled point object at the platform's top-left corner.
+#[derive(Component, Debug, Clone, Reflect)]
+#[reflect(Component, Default)]
+#[require(
+    Sprite,
+    RigidBody::Static,
+    Collider::rectangle(32.0, 9.0),
+    LinearVelocity,
+    CrumbleState
+)]
+#[component(on_add = setup_crumbling_platform_sprite)]
+pub struct CrumblingPlatform {
+    /// Seconds the platform shakes before falling
+    pub crumble_time: f32,
+    /// Seconds after falling until the platform reappears
+    pub respawn_time: f32,
+    /// Resting position (center) - computed from the Tiled point
+    #[reflect(ignore)]
+    pub home: Vec2,
+}
+
+impl Default for CrumblingPlatform {
+    fn default() -> Self {
+        Self {
+            crumble_time: 0.5,
+            respawn_time: 3.0,
+            home: Vec2::ZERO,
+        }
+    }
+}
+
+#[derive(Component, Default, Debug)]
+pub enum CrumbleState {
+    #[default]
+    Intact,
+    Shaking(Timer),
+    Falling(Timer),
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn platform(mode: PathMode, waypoints: &[Vec2]) -> MovingPlatform {
+        MovingPlatform {
+            mode,
+            waypoints: waypoints.to_vec(),
+            ..default()
+        }
+    }
+
+    #[test]
+    fn seek_interpolates_along_the_path() {
+        let mut platform = platform(
+            PathMode::PingPong,
+            &[Vec2::ZERO, Vec2::new(100.0, 0.0), Vec2::new(100.0, 100.0)],
+        );
+
+        assert_eq!(platform.seek(0.25), Vec2::new(50.0, 0.0));
+        assert_eq!((platform.previous, platform.target), (0, 1));
+
+        assert_eq!(platform.seek(0.75), Vec2::new(100.0, 50.0));
+        assert_eq!((platform.previous, platform.target), (1, 2));
+    }
+
+    #[test]
+    fn seek_includes_the_closing_segment_of_a_loop() {
+        let mut platform = platform(
+            PathMode::Loop,
+            &[
+                Vec2::ZERO,
+                Vec2::new(100.0, 0.0),
+                Vec2::new(100.0, 100.0),
+                Vec2::new(0.0, 100.0),
+            ],
+        );
+
+        assert_eq!(platform.seek(0.875), Vec2::new(0.0, 50.0));
+        assert_eq!((platform.previous, platform.target), (3, 0));
+    }
+
+    #[test]
+    fn seek_wraps_fractions_and_heads_forward() {
+        let mut platform = platform(PathMode::PingPong, &[Vec2::ZERO, Vec2::new(100.0, 0.0)]);
+        platform.direction = -1;
+
+        assert_eq!(platform.seek(1.25), Vec2::new(25.0, 0.0));
+        assert_eq!(platform.direction, 1);
+        assert_eq!(platform.seek(1.0), Vec2::ZERO);
+        assert_eq!((platform.previous, platform.target), (0, 1));
+    }
 }
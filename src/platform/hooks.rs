@@ -1,4 +1,5 @@
 use super::components::OneWayPlatform;
+use crate::player::{PlayerState, PlayerStateMachine};
 use avian2d::prelude::*;
 use bevy::{
     ecs::system::{SystemParam, lifetimeless::Read},
@@ -8,6 +9,7 @@ use bevy::{
 #[derive(SystemParam)]
 pub struct PlatformHooks<'w, 's> {
     one_way_platforms_query: Query<'w, 's, (Read<OneWayPlatform>, Read<GlobalTransform>)>,
+    state_machines_query: Query<'w, 's, Read<PlayerStateMachine>>,
 }
 
 impl CollisionHooks for PlatformHooks<'_, '_> {
@@ -24,7 +26,7 @@ impl CollisionHooks for PlatformHooks<'_, '_> {
 
         // First, figure out which entity is the one-way platform, and which is the other.
         // Choose the appropriate normal for pass-through depending on which is which.
-        let (_, _, platform_transform, _, relevant_normal) =
+        let (_, _, platform_transform, other, relevant_normal) =
             if let Ok((one_way_platform, platform_transform)) =
                 self.one_way_platforms_query.get(contacts.collider1)
             {
@@ -51,6 +53,15 @@ impl CollisionHooks for PlatformHooks<'_, '_> {
                 return true;
             };
 
+        // Climbing players pass through, so ladders can lead up through a platform and down from it
+        if self
+            .state_machines_query
+            .get(other)
+            .is_ok_and(|machine| machine.current == PlayerState::Climb)
+        {
+            return false;
+        }
+
         // Get the manifold and check the normal
         for manifold in contacts.manifolds.iter() {
             // Get normal pointing toward the "other" entity
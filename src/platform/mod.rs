@@ -4,5 +4,8 @@ mod plugin;
 mod resources;
 mod systems;
 
+pub use components::MovingPlatform;
 pub use hooks::PlatformHooks;
 pub use plugin::PlatformPlugin;
+#[cfg(test)]
+pub(crate) use {resources::PlatformTexture, systems::update_moving_platforms};
@@ -1,9 +1,11 @@
-use super::components::{MovingPlatform, OneWayPlatform};
-use super::resources::PlatformTexture;
+use super::components::{CrumblingPlatform, MovingPlatform, OneWayPlatform};
+use super::resources::{CrushSettings, PlatformTexture};
 use super::systems::{
-    load_platform_texture, setup_moving_platforms, spawn_platform_at_spawn_point,
-    update_moving_platforms,
+    detect_crushing, load_platform_texture, setup_crumbling_platforms, setup_moving_platforms,
+    spawn_platform_at_spawn_point, trigger_platforms_on_stand, trigger_platforms_on_switch,
+    update_crumbling_platforms, update_moving_platforms,
 };
+use crate::player::PlayerSystemSet;
 use bevy::prelude::*;
 
 pub struct PlatformPlugin;
@@ -12,12 +14,33 @@ impl Plugin for PlatformPlugin {
     fn build(&self, app: &mut App) {
         app.register_type::<OneWayPlatform>()
             .register_type::<MovingPlatform>()
+            .register_type::<CrumblingPlatform>()
             .register_type::<PlatformTexture>()
+            .register_type::<CrushSettings>()
+            .init_resource::<CrushSettings>()
             .add_systems(Startup, load_platform_texture)
             .add_systems(
                 Update,
-                (spawn_platform_at_spawn_point, setup_moving_platforms),
+                (
+                    spawn_platform_at_spawn_point,
+                    setup_moving_platforms,
+                    setup_crumbling_platforms,
+                ),
             )
-            .add_systems(FixedUpdate, update_moving_platforms);
+            .add_systems(Update, trigger_platforms_on_switch)
+            .add_systems(
+                FixedUpdate,
+                (
+                    (
+                        detect_crushing,
+                        trigger_platforms_on_stand,
+                        update_moving_platforms,
+                    )
+                        .chain(),
+                    update_crumbling_platforms,
+                )
+                    // Riders read this tick's platform velocity
+                    .before(PlayerSystemSet::Movement),
+            );
     }
 }
@@ -5,3 +5,17 @@ use bevy::prelude::*;
 pub struct PlatformTexture {
     pub texture: Handle<Image>,
 }
+
+/// Tuning for players and enemies squeezed between a moving platform and solid geometry.
+#[derive(Resource, Reflect)]
+#[reflect(Resource)]
+pub struct CrushSettings {
+    /// Combined penetration in pixels beyond which a squeezed body is crushed
+    pub depth: f32,
+}
+
+impl Default for CrushSettings {
+    fn default() -> Self {
+        Self { depth: 4.0 }
+    }
+}
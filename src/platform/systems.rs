@@ -1,8 +1,18 @@
-use super::components::{MovingPlatform, OneWayPlatform};
-use super::resources::PlatformTexture;
+use super::components::{
+    CrumbleState, CrumblingPlatform, CrushBlocked, MovingPlatform, OneWayPlatform, PlatformTrigger,
+};
+use super::resources::{CrushSettings, PlatformTexture};
+use crate::core::layers::GameLayer;
+use crate::enemy::Enemy;
+use crate::enemy::messages::EnemyDefeated;
+use crate::killzone::components::DeathTimer;
+use crate::killzone::start_death;
+use crate::player::{Player, StandingOn};
+use crate::puzzle::components::SwitchTarget;
+use crate::puzzle::messages::SwitchToggled;
 use crate::state::GameState;
 use avian2d::prelude::*;
-use bevy::prelude::*;
+use bevy::{prelude::*, sprite::Anchor};
 use bevy_ecs_tiled::prelude::*;
 use moonshine_kind::Instance;
 
@@ -35,7 +45,7 @@ pub fn spawn_platform_at_spawn_point(
     }
 }
 
-/// Initializes MovingPlatform start/end from polyline vertices.
+/// Initializes MovingPlatform waypoints from polyline/polygon vertices.
 pub fn setup_moving_platforms(
     maps_assets: Res<Assets<TiledMapAsset>>,
     map_query: Query<&TiledMap>,
@@ -47,11 +57,12 @@ pub fn setup_moving_platforms(
             &TiledMapReference,
             &GlobalTransform,
             &mut Transform,
+            Option<&Name>,
         ),
         Added<MovingPlatform>,
     >,
 ) {
-    for (mut platform, sprite, tiled_obj, map_ref, global_transform, mut transform) in
+    for (mut platform, sprite, tiled_obj, map_ref, global_transform, mut transform, name) in
         &mut platforms
     {
         let Some(vertices) = map_query
@@ -63,47 +74,382 @@ pub fn setup_moving_platforms(
             continue;
         };
 
-        assert!(
-            vertices.len() >= 2,
-            "MovingPlatform polyline needs at least 2 points"
-        );
+        if vertices.len() < 2 {
+            error!(
+                "Map error: MovingPlatform {:?} needs a polyline or polygon with at least 2 points, found {}",
+                name.map(Name::as_str).unwrap_or("<unnamed>"),
+                vertices.len()
+            );
+            continue;
+        }
 
         // Get sprite dimensions from rect
-        let (width, height) = sprite
-            .rect
-            .map(|r| (r.width(), r.height()))
-            .unwrap_or((32.0, 9.0));
+        let height = sprite.rect.map(|r| r.height()).unwrap_or(9.0);
 
-        // First vertex = left edge at start, last = right edge at end
-        let first = vertices.first().unwrap();
-        let last = vertices.last().unwrap();
+        // Each vertex marks the platform's top-center, not its left or right edge
+        // (see `MovingPlatform` for migrating maps drawn edge to edge)
+        platform.waypoints = vertices
+            .iter()
+            .map(|v| Vec2::new(v.x, v.y - height / 2.0))
+            .collect();
 
-        platform.start = Vec2::new(first.x + width / 2.0, first.y - height / 2.0);
-        platform.end = Vec2::new(last.x - width / 2.0, last.y - height / 2.0);
+        let overrides: Vec<Option<f32>> = platform
+            .wait_times
+            .split(',')
+            .map(|w| w.trim().parse().ok())
+            .collect();
+        platform.waits = (0..platform.waypoints.len())
+            .map(|i| {
+                overrides
+                    .get(i)
+                    .copied()
+                    .flatten()
+                    .unwrap_or(platform.wait_time)
+            })
+            .collect();
 
-        // Set initial position
-        transform.translation = platform.start.extend(transform.translation.z);
+        // Set initial position, phase-shifted along the path
+        let offset = platform.start_offset;
+        let start = platform.seek(offset);
+        if platform.trigger != PlatformTrigger::Always {
+            platform.awaiting_trigger = true;
+        } else if offset == 0.0 {
+            platform.wait_remaining = platform.waits[0];
+        }
+        transform.translation = start.extend(transform.translation.z);
     }
 }
 
-/// Animates moving platforms back and forth using LinearVelocity
+/// Moves platforms along their waypoints using LinearVelocity
+///
+/// Platforms linked from a switch only move while the switch powers them.
 pub fn update_moving_platforms(
-    mut platforms: Query<(&mut MovingPlatform, &Transform, &mut LinearVelocity)>,
+    time: Res<Time>,
+    mut platforms: Query<(
+        &mut MovingPlatform,
+        &Transform,
+        &mut LinearVelocity,
+        Option<&SwitchTarget>,
+        Option<&CrushBlocked>,
+    )>,
 ) {
-    for (mut platform, transform, mut velocity) in &mut platforms {
+    let dt = time.delta_secs();
+
+    for (mut platform, transform, mut velocity, switch, blocked) in &mut platforms {
+        let unpowered =
+            platform.trigger == PlatformTrigger::Always && switch.is_some_and(|s| !s.powered);
+        if platform.waypoints.len() < 2
+            || platform.finished
+            || platform.awaiting_trigger
+            || unpowered
+        {
+            velocity.0 = Vec2::ZERO;
+            continue;
+        }
+
+        if platform.wait_remaining > 0.0 {
+            platform.wait_remaining -= dt;
+            velocity.0 = Vec2::ZERO;
+            continue;
+        }
+
         let pos = transform.translation.truncate();
-        let target = if platform.direction > 0.0 {
-            platform.end
-        } else {
-            platform.start
-        };
+        let target = platform.waypoints[platform.target];
         let to_target = target - pos;
+        let distance = to_target.length();
 
-        if to_target.length() < 1.0 {
-            platform.direction *= -1.0;
+        // A safe platform holds still rather than squeezing something further
+        if blocked.is_some_and(|b| to_target.dot(b.0) > 0.0) {
             velocity.0 = Vec2::ZERO;
-        } else {
-            velocity.0 = to_target.normalize() * platform.speed;
+            continue;
+        }
+
+        // Arrive exactly this tick instead of overshooting the waypoint
+        if distance <= platform.speed * dt {
+            velocity.0 = if dt > 0.0 { to_target / dt } else { Vec2::ZERO };
+
+            let arrived = platform.target;
+            platform.wait_remaining = platform.waits[arrived];
+
+            // Triggered platforms rest at the ends, optionally returning by themselves
+            if platform.rests_at(arrived) {
+                let at_start = arrived == 0;
+                if !at_start && platform.return_delay > 0.0 {
+                    platform.wait_remaining = platform.return_delay;
+                } else {
+                    platform.awaiting_trigger = true;
+                }
+            }
+
+            match platform.next_waypoint() {
+                Some(next) => {
+                    platform.previous = arrived;
+                    platform.target = next;
+                }
+                None => platform.finished = true,
+            }
+            continue;
+        }
+
+        // Ease out of the previous stop and into the next one
+        let mut factor: f32 = 1.0;
+        if platform.stops_at(platform.target) {
+            factor = factor.min(platform.easing.apply(distance / platform.ease_distance));
+        }
+        if platform.stops_at(platform.previous) {
+            let travelled = pos.distance(platform.waypoints[platform.previous]);
+            factor = factor.min(platform.easing.apply(travelled / platform.ease_distance));
+        }
+
+        // Keep a minimum speed so the platform can leave a stop
+        velocity.0 = to_target / distance * platform.speed * factor.max(0.15);
+    }
+}
+
+/// Detects players and enemies squeezed between a moving platform and solid geometry.
+/// Crushed players die, crushed enemies die if the platform allows it, and safe
+/// platforms stop instead.
+pub fn detect_crushing(
+    mut commands: Commands,
+    settings: Res<CrushSettings>,
+    collisions: Collisions,
+    spatial_query: SpatialQuery,
+    players: Query<Entity, (With<Player>, Without<DeathTimer>)>,
+    enemies: Query<(Entity, &Collider, &Position, &ColliderAabb), With<Enemy>>,
+    platforms: Query<(Entity, &MovingPlatform, &ColliderAabb, Has<CrushBlocked>)>,
+    bodies: Query<&RigidBody>,
+    sensors: Query<(), With<Sensor>>,
+    mut defeated: MessageWriter<EnemyDefeated>,
+) {
+    // Platforms squeezing something, with the direction toward the squeezed body
+    let mut squeezing: Vec<(Entity, Vec2)> = Vec::new();
+
+    for player in &players {
+        // Contact directions (player -> other) and depths, split by what is touched
+        let mut moving = Vec::new();
+        let mut solid = Vec::new();
+        for pair in collisions.collisions_with(player) {
+            let is_first = pair.collider1 == player;
+            let (other, body) = if is_first {
+                (pair.collider2, pair.body2)
+            } else {
+                (pair.collider1, pair.body1)
+            };
+            if !pair.is_touching() || sensors.contains(other) {
+                continue;
+            }
+            let body = body.unwrap_or(other);
+            for manifold in &pair.manifolds {
+                let dir = if is_first {
+                    manifold.normal
+                } else {
+                    -manifold.normal
+                };
+                let depth = manifold
+                    .points
+                    .iter()
+                    .map(|p| p.penetration)
+                    .fold(0.0, f32::max);
+                match bodies.get(body) {
+                    Ok(RigidBody::Kinematic) => moving.push((body, dir, depth)),
+                    Ok(RigidBody::Static) | Err(_) => solid.push((dir, depth)),
+                    _ => {}
+                }
+            }
+        }
+
+        for (body, dir, depth) in moving {
+            let Ok((platform_entity, platform, ..)) = platforms.get(body) else {
+                continue;
+            };
+            // Solid geometry on the opposite side of the platform
+            let Some(solid_depth) = solid
+                .iter()
+                .filter(|(solid_dir, _)| dir.dot(*solid_dir) < -0.5)
+                .map(|(_, d)| *d)
+                .reduce(f32::max)
+            else {
+                continue;
+            };
+
+            if platform.safe {
+                squeezing.push((platform_entity, -dir));
+            } else if depth + solid_depth > settings.depth {
+                info!("Player crushed by moving platform! Starting death timer...");
+                start_death(&mut commands, player);
+                break;
+            }
+        }
+    }
+
+    // Enemies are sensors, so compare bounds and probe for solid geometry behind them
+    let walls = SpatialQueryFilter::from_mask(GameLayer::Wall);
+    for (enemy, collider, position, enemy_aabb) in &enemies {
+        for (platform_entity, platform, aabb, _) in &platforms {
+            let overlap = enemy_aabb.max.min(aabb.max) - enemy_aabb.min.max(aabb.min);
+            if overlap.x <= 0.0 || overlap.y <= 0.0 {
+                continue;
+            }
+
+            // Push direction is along the axis of least overlap, away from the platform
+            let offset = enemy_aabb.center() - aabb.center();
+            let (push, depth) = if overlap.x < overlap.y {
+                (Vec2::new(offset.x.signum(), 0.0), overlap.x)
+            } else {
+                (Vec2::new(0.0, offset.y.signum()), overlap.y)
+            };
+            let Ok(direction) = Dir2::new(push) else {
+                continue;
+            };
+
+            // Ignore geometry already touched, e.g. the floor when pushed sideways
+            let config = ShapeCastConfig {
+                max_distance: 1.0,
+                ignore_origin_penetration: true,
+                ..default()
+            };
+            let wedged = spatial_query
+                .cast_shape(collider, position.0, 0.0, direction, &config, &walls)
+                .is_some();
+            if !wedged {
+                continue;
+            }
+
+            if platform.safe {
+                squeezing.push((platform_entity, push));
+            } else if platform.crush_enemies && depth > settings.depth {
+                defeated.write(EnemyDefeated {
+                    position: position.0,
+                });
+                commands.entity(enemy).despawn();
+                break;
+            }
+        }
+    }
+
+    for (entity, _, _, blocked) in &platforms {
+        match squeezing.iter().find(|(platform, _)| *platform == entity) {
+            Some((_, dir)) => {
+                commands.entity(entity).insert(CrushBlocked(*dir));
+            }
+            None if blocked => {
+                commands.entity(entity).remove::<CrushBlocked>();
+            }
+            None => {}
+        }
+    }
+}
+
+/// Starts triggered platforms when the player lands on them.
+pub fn trigger_platforms_on_stand(
+    players: Query<&StandingOn>,
+    mut platforms: Query<(Entity, &mut MovingPlatform)>,
+) {
+    for (entity, mut platform) in &mut platforms {
+        if platform.trigger != PlatformTrigger::OnStand {
+            continue;
+        }
+
+        // Only a fresh landing triggers, so a rider arriving at the end isn't sent straight back
+        let ridden = players.iter().any(|standing_on| standing_on.0 == entity);
+        if ridden && !platform.ridden && platform.awaiting_trigger {
+            platform.awaiting_trigger = false;
+        }
+        if platform.ridden != ridden {
+            platform.ridden = ridden;
+        }
+    }
+}
+
+/// Starts triggered platforms when a linked switch turns on.
+pub fn trigger_platforms_on_switch(
+    mut messages: MessageReader<SwitchToggled>,
+    mut platforms: Query<&mut MovingPlatform>,
+) {
+    for evt in messages.read() {
+        if let Ok(mut platform) = platforms.get_mut(evt.target)
+            && evt.on
+            && platform.trigger == PlatformTrigger::OnSwitch
+            && platform.awaiting_trigger
+        {
+            platform.awaiting_trigger = false;
+        }
+    }
+}
+
+/// Centers crumbling platforms on their Tiled point and remembers where they rest.
+pub fn setup_crumbling_platforms(
+    mut platforms: Query<(&mut CrumblingPlatform, &mut Transform), Added<CrumblingPlatform>>,
+) {
+    for (mut platform, mut transform) in &mut platforms {
+        // Tiled point is top-left, offset to center for Bevy (32x9 platform)
+        transform.translation.x += 16.0;
+        transform.translation.y -= 4.5;
+        platform.home = transform.translation.truncate();
+    }
+}
+
+/// Shakes crumbling platforms once stood on, drops them, and restores them after a delay.
+pub fn update_crumbling_platforms(
+    mut commands: Commands,
+    time: Res<Time>,
+    gravity: Res<Gravity>,
+    players: Query<&StandingOn>,
+    mut platforms: Query<(
+        Entity,
+        &CrumblingPlatform,
+        &mut CrumbleState,
+        &mut Anchor,
+        &mut Transform,
+        &mut LinearVelocity,
+    )>,
+) {
+    for (entity, platform, mut state, mut anchor, mut transform, mut velocity) in &mut platforms {
+        match &mut *state {
+            CrumbleState::Intact => {
+                if players.iter().any(|standing_on| standing_on.0 == entity) {
+                    *state = CrumbleState::Shaking(Timer::from_seconds(
+                        platform.crumble_time,
+                        TimerMode::Once,
+                    ));
+                }
+            }
+            CrumbleState::Shaking(timer) => {
+                timer.tick(time.delta());
+
+                // Jitter the sprite only, so the collider stays put under the player
+                let jitter = if ((timer.elapsed_secs() * 30.0) as u32).is_multiple_of(2) {
+                    1.0
+                } else {
+                    -1.0
+                };
+                *anchor = Anchor::from(Vec2::new(jitter / 32.0, 0.0));
+
+                if timer.is_finished() {
+                    *anchor = Anchor::CENTER;
+                    commands
+                        .entity(entity)
+                        .insert((RigidBody::Kinematic, ColliderDisabled));
+                    *state = CrumbleState::Falling(Timer::from_seconds(
+                        platform.respawn_time,
+                        TimerMode::Once,
+                    ));
+                }
+            }
+            CrumbleState::Falling(timer) => {
+                timer.tick(time.delta());
+                velocity.0 += gravity.0 * time.delta_secs();
+
+                if timer.is_finished() {
+                    velocity.0 = Vec2::ZERO;
+                    transform.translation = platform.home.extend(transform.translation.z);
+                    commands
+                        .entity(entity)
+                        .insert((RigidBody::Static, Position(platform.home)))
+                        .remove::<ColliderDisabled>();
+                    *state = CrumbleState::Intact;
+                }
+            }
         }
     }
 }
@@ -1,4 +1,7 @@
 use super::resources::KnightAtlas;
+use super::tuning::PlayerTuning;
+use crate::aseprite::AsepriteSheet;
+use crate::aseprite::components::AsepriteAnimation;
 use crate::core::components::Speed;
 use avian2d::prelude::*;
 use bevy::{
@@ -7,20 +10,32 @@ use bevy::{
     sprite::Anchor,
 };
 
+/// Radius and segment length of the player's capsule collider
+const PLAYER_RADIUS: f32 = 3.0;
+const PLAYER_LENGTH: f32 = 5.0;
+
+/// Distance from the player's center to their feet, and to the top of their head
+pub(crate) const PLAYER_HALF_HEIGHT: f32 = PLAYER_RADIUS + PLAYER_LENGTH / 2.0;
+
 #[derive(Component, Reflect, Default)]
 #[reflect(Component)]
 #[require(
     Name = "Player",
     Sprite,
     Anchor = Anchor::from(Vec2::new(0.0, -0.175)),
+    PlayerStateMachine,
     PlayerAnimation,
     RigidBody::Dynamic,
-    Collider = Collider::capsule(3.0, 5.0),
-    ShapeCaster = ShapeCaster::new(Collider::capsule(2.97, 4.95), Vec2::ZERO, 0.0, Dir2::NEG_Y).with_max_distance(2.0).with_max_hits(10),
+    Collider = Collider::capsule(PLAYER_RADIUS, PLAYER_LENGTH),
+    ShapeCaster = ShapeCaster::new(Collider::capsule(PLAYER_RADIUS * 0.99, PLAYER_LENGTH * 0.99), Vec2::ZERO, 0.0, Dir2::NEG_Y).with_max_distance(2.0).with_max_hits(10),
     Friction::ZERO,
+    GravityScale(1.0),
     LockedAxes = LockedAxes::ROTATION_LOCKED,
-    Speed = Speed(130.0),
-    JumpVelocity = JumpVelocity(300.0),
+    Speed = Speed(PlayerTuning::default().run_speed),
+    JumpVelocity = JumpVelocity(PlayerTuning::default().jump_velocity),
+    Dash,
+    AirJumps,
+    RunVelocity,
   )]
 #[component(on_add = Self::on_add)]
 pub struct Player;
@@ -48,14 +63,131 @@ impl Player {
 #[derive(Component, Default)]
 pub struct Grounded;
 
+/// Extra jumps the player can make in mid-air, refilled on landing.
+#[derive(Component, Reflect, Debug, Clone)]
+#[reflect(Component, Default)]
+pub struct AirJumps {
+    /// Base number of air jumps
+    pub count: u32,
+    /// Jump velocity of an air jump
+    pub velocity: f32,
+    /// Air jumps granted by unlocked abilities
+    #[reflect(ignore)]
+    pub unlocked: u32,
+    /// Air jumps granted by active power-ups
+    #[reflect(ignore)]
+    pub bonus: u32,
+    /// Air jumps left before landing
+    #[reflect(ignore)]
+    pub remaining: u32,
+}
+
+impl Default for AirJumps {
+    fn default() -> Self {
+        Self {
+            count: 0,
+            velocity: 260.0,
+            unlocked: 0,
+            bonus: 0,
+            remaining: 0,
+        }
+    }
+}
+
+impl AirJumps {
+    /// Total air jumps per airtime.
+    pub fn max(&self) -> u32 {
+        self.count + self.unlocked + self.bonus
+    }
+}
+
+/// Dash tuning, usable once `Ability::Dash` is unlocked.
+#[derive(Component, Reflect, Debug, Clone)]
+#[reflect(Component, Default)]
+pub struct Dash {
+    /// Horizontal dash velocity in pixels per second
+    pub speed: f32,
+    /// Seconds the dash overrides velocity and gravity
+    pub duration: f32,
+    /// Seconds after a dash starts before the next one
+    pub cooldown: f32,
+    /// Enemies don't hurt the player while dashing
+    pub through_enemies: bool,
+    /// Last facing direction from `PlayerMovement`
+    #[reflect(ignore)]
+    pub facing_left: bool,
+}
+
+impl Default for Dash {
+    fn default() -> Self {
+        Self {
+            speed: 320.0,
+            duration: 0.15,
+            cooldown: 0.5,
+            through_enemies: false,
+            facing_left: false,
+        }
+    }
+}
+
+/// The dash in progress, present only in the Dash state.
+#[derive(Component)]
+pub struct Dashing {
+    pub timer: Timer,
+    /// -1 = left, 1 = right
+    pub direction: f32,
+    pub through_enemies: bool,
+}
+
+/// Time until the player can dash again.
+#[derive(Component)]
+pub struct DashCooldown(pub Timer);
+
+/// The player has used their dash for this airtime. Removed on landing.
+#[derive(Component, Default)]
+pub struct AirDashUsed;
+
+/// The player is within a ladder's climbable zone.
+#[derive(Component, Clone, Copy)]
+pub struct OnLadder {
+    /// Climbing speed of the ladder in pixels per second
+    pub speed: f32,
+    /// Feet are at the ladder's bottom edge
+    pub at_bottom: bool,
+    /// Feet are at or above the ladder's top edge
+    pub at_top: bool,
+}
+
+/// The player is in a water volume and uses its swimming physics.
+#[derive(Component, Clone, Copy)]
+pub struct Swimming {
+    /// The water volume's entity
+    pub water: Entity,
+    /// World-space height of the water surface
+    pub surface: f32,
+    pub gravity_scale: f32,
+    pub drag: f32,
+    pub max_fall_speed: f32,
+    pub stroke_velocity: f32,
+}
+
+/// Closest upward-facing surface below the player, including slopes too steep to stand on.
+#[derive(Component, Clone, Copy)]
+pub struct GroundHit {
+    /// World-space surface normal
+    pub normal: Vec2,
+    /// Gap between the player and the surface in pixels
+    pub distance: f32,
+}
+
 /// Allows jumping for a short window after leaving ground.
 #[derive(Component, Reflect)]
 #[reflect(Component)]
 pub struct CoyoteTimer(pub Timer);
 
-impl Default for CoyoteTimer {
-    fn default() -> Self {
-        Self(Timer::from_seconds(0.1, TimerMode::Once))
+impl CoyoteTimer {
+    pub fn new(seconds: f32) -> Self {
+        Self(Timer::from_seconds(seconds, TimerMode::Once))
     }
 }
 
@@ -64,15 +196,37 @@ impl Default for CoyoteTimer {
 #[reflect(Component)]
 pub struct JumpBuffer(pub Timer);
 
-impl Default for JumpBuffer {
-    fn default() -> Self {
-        Self(Timer::from_seconds(0.1, TimerMode::Once))
+impl JumpBuffer {
+    pub fn new(seconds: f32) -> Self {
+        Self(Timer::from_seconds(seconds, TimerMode::Once))
     }
 }
 
+/// The player's own horizontal velocity from input, before platform and conveyor
+/// velocities are added on top.
+#[derive(Component, Default)]
+pub struct RunVelocity(pub f32);
+
 /// Velocity inherited from the platform the player is standing on.
 #[derive(Component, Default)]
-pub struct PlatformVelocity(pub Vec2);
+pub struct PlatformVelocity {
+    pub current: Vec2,
+    /// The platform velocity the player was carried with last tick, which is still in
+    /// their velocity until this tick's movement replaces it
+    pub previous: Vec2,
+}
+
+/// Horizontal platform velocity kept in the air after leaving a moving platform.
+#[derive(Component, Default)]
+pub struct PlatformMomentum(pub f32);
+
+/// Horizontal velocity from a conveyor surface the player is standing on.
+#[derive(Component, Default)]
+pub struct SurfaceVelocity(pub f32);
+
+/// Ground entity the player is standing on.
+#[derive(Component)]
+pub struct StandingOn(pub Entity);
 
 #[derive(Component, Default)]
 pub struct WallContactLeft;
@@ -84,6 +238,80 @@ pub struct WallContactRight;
 #[reflect(Component)]
 pub struct JumpVelocity(pub f32);
 
+/// High-level player state, driven by `update_player_state`.
+#[derive(Reflect, Default, Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum PlayerState {
+    #[default]
+    Idle,
+    Run,
+    /// Leaving the ground or a ladder; the jump is launched on entering
+    Jump,
+    /// Airborne and moving up
+    Rise,
+    /// Airborne and moving down
+    Fall,
+    /// Somersaulting after an air jump, launched on entering, until landing
+    Flip,
+    /// Just touched down, briefly before Idle
+    Land,
+    Climb,
+    Swim,
+    Dash,
+    Dead,
+    /// Posing after respawning, before control returns
+    Respawn,
+}
+
+/// Parent states grouping the leaf states of `PlayerState`.
+#[derive(Reflect, Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum PlayerStateGroup {
+    Grounded,
+    Airborne,
+    /// States with their own movement rules
+    Special,
+}
+
+impl PlayerState {
+    pub fn group(self) -> PlayerStateGroup {
+        match self {
+            Self::Idle | Self::Run | Self::Land => PlayerStateGroup::Grounded,
+            Self::Jump | Self::Rise | Self::Fall | Self::Flip => PlayerStateGroup::Airborne,
+            Self::Climb | Self::Swim | Self::Dash | Self::Dead | Self::Respawn => {
+                PlayerStateGroup::Special
+            }
+        }
+    }
+
+    /// The animation shown in this state.
+    pub fn animation(self) -> PlayerAnimation {
+        match self {
+            Self::Idle | Self::Land => PlayerAnimation::Idle,
+            Self::Run | Self::Dash => PlayerAnimation::Run,
+            Self::Jump | Self::Rise | Self::Fall | Self::Swim => PlayerAnimation::Jump,
+            Self::Flip => PlayerAnimation::Flip,
+            Self::Climb => PlayerAnimation::Climb,
+            Self::Dead => PlayerAnimation::Death,
+            Self::Respawn => PlayerAnimation::Respawn,
+        }
+    }
+}
+
+/// The player's state machine: current state and how long it has been active.
+///
+/// `update_player_state` picks the state each tick from input and what the player is
+/// touching, before anything moves. Moves are transitions into a state: jumps, air jumps,
+/// dashes and ladder grabs are launched by that state's enter hook, and each state's
+/// movement system only runs in it. The state also drives the animation, sounds and particles.
+#[derive(Component, Reflect, Default, Debug)]
+#[reflect(Component)]
+pub struct PlayerStateMachine {
+    pub current: PlayerState,
+    pub previous: PlayerState,
+    /// Seconds spent in the current state
+    pub elapsed: f32,
+}
+
+/// Projection of `PlayerState` onto the knight atlas, kept in sync by `update_player_animation`.
 #[derive(Component, Reflect, Default, Clone, Copy, PartialEq, Eq)]
 #[reflect(Component)]
 pub enum PlayerAnimation {
@@ -91,14 +319,36 @@ pub enum PlayerAnimation {
     Idle,
     Run,
     Jump,
+    Climb,
+    Flip,
+    Death,
+    Respawn,
 }
 
+/// Frame event written when a foot hits the ground in the run cycle.
+pub const FOOTSTEP: &str = "footstep";
+
 impl PlayerAnimation {
-    pub fn frames(self) -> (usize, usize) {
+    /// Tag of the knight sheet played for this animation.
+    pub fn tag(self) -> &'static str {
+        match self {
+            Self::Idle => "idle",
+            Self::Run => "run",
+            Self::Jump => "jump",
+            Self::Climb => "climb",
+            Self::Flip => "roll",
+            Self::Death => "death",
+            Self::Respawn => "get_up",
+        }
+    }
+
+    /// The knight sheet animation for this state, with its frame events.
+    pub fn aseprite_animation(self, sheet: Handle<AsepriteSheet>) -> AsepriteAnimation {
+        let animation = AsepriteAnimation::new(sheet, self.tag());
         match self {
-            Self::Idle => (0, 3),
-            Self::Run => (16, 31),
-            Self::Jump => (42, 42),
+            // Feet touch down on the 4th and 12th frames of the run cycle
+            Self::Run => animation.with_event(3, FOOTSTEP).with_event(11, FOOTSTEP),
+            _ => animation,
         }
     }
 }
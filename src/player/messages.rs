@@ -1,3 +1,4 @@
+use super::components::PlayerState;
 use bevy::prelude::*;
 
 #[derive(Message, Default)]
@@ -5,3 +6,25 @@ pub struct PlayerMovement {
     pub is_moving: bool,
     pub facing_left: bool,
 }
+
+/// Written whenever the player's state machine changes state.
+#[derive(Message, Debug, Clone, Copy)]
+pub struct PlayerStateChanged {
+    pub player: Entity,
+    pub from: PlayerState,
+    pub to: PlayerState,
+}
+
+/// Triggered on the player when it enters a state. Observe it for per-state enter hooks.
+#[derive(EntityEvent, Debug, Clone, Copy)]
+pub struct EnterPlayerState {
+    pub entity: Entity,
+    pub state: PlayerState,
+}
+
+/// Triggered on the player when it leaves a state. Observe it for per-state exit hooks.
+#[derive(EntityEvent, Debug, Clone, Copy)]
+pub struct ExitPlayerState {
+    pub entity: Entity,
+    pub state: PlayerState,
+}
@@ -3,6 +3,16 @@ mod messages;
 mod plugin;
 mod resources;
 mod systems;
+#[cfg(test)]
+mod tests;
+mod tuning;
 
-pub use components::Player;
-pub use plugin::PlayerPlugin;
+pub(crate) use components::PLAYER_HALF_HEIGHT;
+pub use components::{
+    AirJumps, Dashing, FOOTSTEP, Grounded, JumpVelocity, OnLadder, Player, PlayerState,
+    PlayerStateGroup, PlayerStateMachine, StandingOn, SurfaceVelocity, Swimming,
+};
+pub use messages::{EnterPlayerState, ExitPlayerState, PlayerStateChanged};
+pub use plugin::{PlayerPlugin, PlayerSystemSet};
+pub use resources::PlayerInput;
+pub use tuning::PlayerTuning;
@@ -1,18 +1,24 @@
-use super::components::{CoyoteTimer, JumpBuffer, JumpVelocity, Player, PlayerAnimation};
-use super::messages::PlayerMovement;
+use super::components::{
+    AirJumps, CoyoteTimer, Dash, JumpBuffer, JumpVelocity, Player, PlayerAnimation, PlayerState,
+    PlayerStateGroup, PlayerStateMachine,
+};
+use super::messages::{PlayerMovement, PlayerStateChanged};
 use super::resources::{KnightAtlas, PlayerInput};
 use super::systems::{
-    apply_player_movement, clear_coyote_timer, detect_player_input, flip_player_sprite,
-    load_knight_atlas, start_coyote_timer, sync_player_animation, tick_coyote_timer,
-    tick_jump_buffer, update_grounded, update_platform_velocity, update_player_animation,
-    update_wall_contact,
+    apply_air_movement, apply_climbing, apply_dash, apply_ground_movement, apply_player_tuning,
+    clear_coyote_timer, detect_player_input, end_dash, flip_player_sprite, grab_ladder,
+    launch_air_jump, launch_jump, load_knight_atlas, load_player_tuning, refill_air_moves_on_land,
+    start_coyote_timer, start_dash, sync_player_animation, tick_coyote_timer, tick_dash,
+    tick_dash_cooldown, tick_jump_buffer, update_grounded, update_platform_velocity,
+    update_player_animation, update_player_state, update_wall_contact,
 };
+use super::tuning::{PlayerTuning, PlayerTuningLoader};
 use bevy::prelude::*;
 
 /// System sets for player operations with better parallelization
 #[derive(SystemSet, Debug, Clone, PartialEq, Eq, Hash)]
 pub enum PlayerSystemSet {
-    /// Physics and movement (runs in FixedUpdate)
+    /// State machine and per-state movement (runs in FixedUpdate)
     Movement,
     /// Animation updates (runs after movement)
     Animation,
@@ -28,9 +34,19 @@ impl Plugin for PlayerPlugin {
             .register_type::<JumpVelocity>()
             .register_type::<CoyoteTimer>()
             .register_type::<JumpBuffer>()
+            .register_type::<Dash>()
+            .register_type::<AirJumps>()
             .register_type::<PlayerInput>()
             .init_resource::<PlayerInput>()
+            .register_type::<PlayerTuning>()
+            .init_resource::<PlayerTuning>()
+            .init_asset::<PlayerTuning>()
+            .init_asset_loader::<PlayerTuningLoader>()
+            .register_type::<PlayerState>()
+            .register_type::<PlayerStateGroup>()
+            .register_type::<PlayerStateMachine>()
             .add_message::<PlayerMovement>()
+            .add_message::<PlayerStateChanged>()
             // Configure set ordering for FixedUpdate
             .configure_sets(
                 FixedUpdate,
@@ -38,7 +54,8 @@ impl Plugin for PlayerPlugin {
             )
             // Input detection runs in Update (every frame) for responsive input
             .add_systems(Update, detect_player_input)
-            .add_systems(Startup, load_knight_atlas)
+            .add_systems(Startup, (load_knight_atlas, load_player_tuning))
+            .add_systems(Update, apply_player_tuning)
             // Movement and animation run in FixedUpdate (synced with physics)
             .add_systems(
                 FixedUpdate,
@@ -54,8 +71,16 @@ impl Plugin for PlayerPlugin {
                             clear_coyote_timer,
                             tick_coyote_timer,
                             tick_jump_buffer,
+                            tick_dash,
+                            tick_dash_cooldown,
+                        ),
+                        update_player_state,
+                        (
+                            apply_ground_movement,
+                            apply_air_movement,
+                            apply_climbing,
+                            apply_dash,
                         ),
-                        apply_player_movement,
                     )
                         .chain()
                         .in_set(PlayerSystemSet::Movement),
@@ -67,6 +92,13 @@ impl Plugin for PlayerPlugin {
                         .chain()
                         .in_set(PlayerSystemSet::Animation),
                 ),
-            );
+            )
+            // Per-state enter/exit hooks
+            .add_observer(launch_jump)
+            .add_observer(launch_air_jump)
+            .add_observer(start_dash)
+            .add_observer(end_dash)
+            .add_observer(grab_ladder)
+            .add_observer(refill_air_moves_on_land);
     }
 }
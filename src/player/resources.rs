@@ -1,3 +1,4 @@
+use crate::aseprite::AsepriteSheet;
 use bevy::prelude::*;
 
 #[derive(Resource, Default, Reflect)]
@@ -5,11 +6,19 @@ use bevy::prelude::*;
 pub struct KnightAtlas {
     pub texture: Handle<Image>,
     pub layout: Handle<TextureAtlasLayout>,
+    /// Animation tags of the knight sheet
+    pub sheet: Handle<AsepriteSheet>,
 }
 
 #[derive(Resource, Default, Reflect)]
 #[reflect(Resource)]
 pub struct PlayerInput {
     pub movement_direction: f32,
+    /// -1 = down, 1 = up, used for climbing
+    pub vertical_direction: f32,
     pub jump_requested: bool,
+    /// Set on dash press, cleared by the dash system
+    pub dash_requested: bool,
+    /// Set on interact press, cleared by whichever system handles it
+    pub interact_requested: bool,
 }
@@ -1,66 +1,131 @@
 use super::components::{
-    CoyoteTimer, Grounded, JumpBuffer, JumpVelocity, PlatformVelocity, Player, PlayerAnimation,
-    WallContactLeft, WallContactRight,
+    AirDashUsed, AirJumps, CoyoteTimer, Dash, DashCooldown, Dashing, GroundHit, Grounded,
+    JumpBuffer, JumpVelocity, OnLadder, PLAYER_HALF_HEIGHT, PlatformMomentum, PlatformVelocity,
+    Player, PlayerAnimation, PlayerState, PlayerStateGroup, PlayerStateMachine, RunVelocity,
+    StandingOn, SurfaceVelocity, Swimming, WallContactLeft, WallContactRight,
 };
-use super::messages::PlayerMovement;
+use super::messages::{EnterPlayerState, ExitPlayerState, PlayerMovement, PlayerStateChanged};
 use super::resources::{KnightAtlas, PlayerInput};
-use crate::core::components::{Speed, SpriteAnimation};
-use crate::killzone::components::DeathTimer;
+use super::tuning::{PlayerTuning, PlayerTuningHandle};
+use crate::abilities::components::Ability;
+use crate::abilities::resources::UnlockedAbilities;
+use crate::core::components::Speed;
+use crate::killzone::components::{DeathTimer, Respawning};
+use crate::powerups::components::{JumpBoost, SpeedBoost};
+use crate::time_scale::resources::TimeScale;
 use avian2d::prelude::*;
 use bevy::prelude::*;
 use moonshine_kind::Instance;
 
-/// System that loads the knight texture atlas and stores it in a resource
-pub fn load_knight_atlas(
-    mut commands: Commands,
-    asset_server: Res<AssetServer>,
-    mut layouts: ResMut<Assets<TextureAtlasLayout>>,
-) {
-    let texture = asset_server.load("sprites/knight.png");
-    let layout = layouts.add(TextureAtlasLayout::from_grid(
-        UVec2::splat(32),
-        8,
-        8,
-        None,
-        None,
+/// System that loads the knight sprite sheet and stores it in a resource
+pub fn load_knight_atlas(mut commands: Commands, asset_server: Res<AssetServer>) {
+    commands.insert_resource(KnightAtlas {
+        texture: asset_server.load("sprites/knight.png"),
+        layout: asset_server.load("sprites/knight.aseprite.json#layout"),
+        sheet: asset_server.load("sprites/knight.aseprite.json"),
+    });
+}
+
+/// Ground closer than this below the feet turns a mid-air jump press into a buffered jump
+const AIR_JUMP_GROUND_MARGIN: f32 = 12.0;
+
+/// Gap in pixels beyond which a grounded player is pulled down onto the ground,
+/// e.g. when walking over the crest of a downward slope.
+pub(super) const GROUND_SNAP_GAP: f32 = 0.5;
+
+/// Starts loading the player tuning asset.
+pub fn load_player_tuning(mut commands: Commands, asset_server: Res<AssetServer>) {
+    commands.insert_resource(PlayerTuningHandle(
+        asset_server.load("tuning/player.tuning.ron"),
     ));
+}
 
-    commands.insert_resource(KnightAtlas { texture, layout });
+/// Copies the tuning asset into the `PlayerTuning` resource whenever it (re)loads,
+/// and applies it to gravity and to the player's speed and jump velocity.
+pub fn apply_player_tuning(
+    mut events: MessageReader<AssetEvent<PlayerTuning>>,
+    handle: Res<PlayerTuningHandle>,
+    assets: Res<Assets<PlayerTuning>>,
+    mut tuning: ResMut<PlayerTuning>,
+    mut gravity: ResMut<Gravity>,
+    new_players: Query<(), Added<Player>>,
+    mut players: Query<
+        (
+            &mut Speed,
+            &mut JumpVelocity,
+            Option<&SpeedBoost>,
+            Option<&JumpBoost>,
+        ),
+        With<Player>,
+    >,
+) {
+    let reloaded = events.read().fold(false, |reloaded, event| {
+        reloaded || event.is_loaded_with_dependencies(&handle.0) || event.is_modified(&handle.0)
+    });
+    if reloaded && let Some(asset) = assets.get(&handle.0) {
+        info!("Applying player tuning");
+        *tuning = asset.clone();
+    }
+    if !reloaded && new_players.is_empty() {
+        return;
+    }
+
+    gravity.0 = Vec2::new(0.0, tuning.gravity);
+    // Keep active power-up multipliers on top of the tuned values
+    for (mut speed, mut jump, speed_boost, jump_boost) in &mut players {
+        speed.0 = tuning.run_speed * speed_boost.map_or(1.0, |b| b.0);
+        jump.0 = tuning.jump_velocity * jump_boost.map_or(1.0, |b| b.0);
+    }
 }
 
 /// System that checks if the player is grounded using ShapeCaster hits.
 pub fn update_grounded(
     mut commands: Commands,
+    tuning: Res<PlayerTuning>,
     query: Query<(Instance<Player>, &ShapeHits, &Rotation)>,
     sensors: Query<(), With<Sensor>>,
 ) {
     for (player, hits, rotation) in &query {
-        // Grounded if shape caster has a hit with a roughly upward normal (ignoring sensors)
-        let is_grounded = hits.iter().any(|hit| {
-            // Skip sensors (like coins)
-            if sensors.contains(hit.entity) {
-                return false;
-            }
-            (rotation * -hit.normal2).angle_to(Vec2::Y).abs() <= std::f32::consts::FRAC_PI_4
-        });
+        // Most upward-facing surface below the player (ignoring sensors like coins)
+        let ground = hits
+            .iter()
+            .filter(|hit| !sensors.contains(hit.entity))
+            .map(|hit| GroundHit {
+                normal: rotation * -hit.normal2,
+                distance: hit.distance,
+            })
+            .filter(|ground| ground.normal.y > 0.0)
+            .max_by(|a, b| a.normal.y.total_cmp(&b.normal.y));
+
+        // Grounded if that surface is no steeper than the max slope angle; steeper slopes slide
+        let max_angle = tuning.max_slope_angle.to_radians();
+        let is_grounded = ground.is_some_and(|g| g.normal.angle_to(Vec2::Y).abs() <= max_angle);
 
         if is_grounded {
             commands.entity(*player).insert(Grounded);
         } else {
             commands.entity(*player).remove::<Grounded>();
         }
+
+        match ground {
+            Some(ground) => commands.entity(*player).insert(ground),
+            None => commands.entity(*player).remove::<GroundHit>(),
+        };
     }
 }
 
 /// Starts coyote timer when player leaves ground.
 pub fn start_coyote_timer(
     mut commands: Commands,
+    tuning: Res<PlayerTuning>,
     mut removed: RemovedComponents<Grounded>,
     players: Query<(), With<Player>>,
 ) {
     for entity in removed.read() {
         if players.contains(entity) {
-            commands.entity(entity).insert(CoyoteTimer::default());
+            commands
+                .entity(entity)
+                .insert(CoyoteTimer::new(tuning.coyote_time));
         }
     }
 }
@@ -103,30 +168,57 @@ pub fn tick_jump_buffer(
     }
 }
 
-/// Tracks velocity from moving platforms the player is standing on.
+/// Tracks the ground entity and velocity from moving platforms the player is standing on.
+///
+/// Leaving a moving platform keeps its horizontal velocity as momentum until landing.
 pub fn update_platform_velocity(
     mut commands: Commands,
-    query: Query<(Instance<Player>, &ShapeHits, &Rotation)>,
+    tuning: Res<PlayerTuning>,
+    query: Query<(
+        Instance<Player>,
+        &ShapeHits,
+        &Rotation,
+        Option<&PlatformVelocity>,
+    )>,
     sensors: Query<(), With<Sensor>>,
-    velocities: Query<&LinearVelocity>,
+    platforms: Query<(&RigidBody, &LinearVelocity), Without<Player>>,
 ) {
-    for (player, hits, rotation) in &query {
+    for (player, hits, rotation, previous) in &query {
         // Find ground hit
         let ground_hit = hits.iter().find(|hit| {
             if sensors.contains(hit.entity) {
                 return false;
             }
-            (rotation * -hit.normal2).angle_to(Vec2::Y).abs() <= std::f32::consts::FRAC_PI_4
+            (rotation * -hit.normal2).angle_to(Vec2::Y).abs() <= tuning.max_slope_angle.to_radians()
         });
 
         if let Some(hit) = ground_hit {
-            if let Ok(vel) = velocities.get(hit.entity) {
-                commands.entity(*player).insert(PlatformVelocity(vel.0));
+            commands
+                .entity(*player)
+                .insert(StandingOn(hit.entity))
+                .remove::<PlatformMomentum>();
+            // Only kinematic bodies carry the player; static ground never moves
+            if let Some((_, vel)) = platforms
+                .get(hit.entity)
+                .ok()
+                .filter(|(body, _)| body.is_kinematic())
+            {
+                commands.entity(*player).insert(PlatformVelocity {
+                    current: vel.0,
+                    previous: previous.map_or(Vec2::ZERO, |p| p.current),
+                });
             } else {
                 commands.entity(*player).remove::<PlatformVelocity>();
             }
         } else {
-            commands.entity(*player).remove::<PlatformVelocity>();
+            commands
+                .entity(*player)
+                .remove::<(StandingOn, PlatformVelocity)>();
+            if let Some(previous) = previous {
+                commands
+                    .entity(*player)
+                    .insert(PlatformMomentum(previous.current.x));
+            }
         }
     }
 }
@@ -191,7 +283,19 @@ pub fn update_wall_contact(
 ///
 /// Runs in InputDetection set and can execute in parallel with other input systems.
 /// Only reads input and writes events, enabling better parallelization.
-pub fn detect_player_input(keyboard: Res<ButtonInput<KeyCode>>, mut input: ResMut<PlayerInput>) {
+///
+/// Records nothing while paused, dropping requests made before the pause too, so nothing
+/// pressed during the pause fires on resume.
+pub fn detect_player_input(
+    keyboard: Res<ButtonInput<KeyCode>>,
+    time_scale: Res<TimeScale>,
+    mut input: ResMut<PlayerInput>,
+) {
+    if time_scale.is_paused() {
+        *input = PlayerInput::default();
+        return;
+    }
+
     // Movement - overwrite each frame
     input.movement_direction = 0.0;
     if keyboard.pressed(KeyCode::KeyA) || keyboard.pressed(KeyCode::ArrowLeft) {
@@ -201,83 +305,218 @@ pub fn detect_player_input(keyboard: Res<ButtonInput<KeyCode>>, mut input: ResMu
         input.movement_direction += 1.0;
     }
 
+    input.vertical_direction = 0.0;
+    if keyboard.pressed(KeyCode::KeyS) || keyboard.pressed(KeyCode::ArrowDown) {
+        input.vertical_direction -= 1.0;
+    }
+    if keyboard.pressed(KeyCode::KeyW) || keyboard.pressed(KeyCode::ArrowUp) {
+        input.vertical_direction += 1.0;
+    }
+
     // Jump - buffer it (don't clear, let movement system clear it)
     if keyboard.just_pressed(KeyCode::Space) {
         input.jump_requested = true;
     }
+
+    if keyboard.just_pressed(KeyCode::ShiftLeft) || keyboard.just_pressed(KeyCode::KeyK) {
+        input.dash_requested = true;
+    }
+
+    if keyboard.just_pressed(KeyCode::KeyE) {
+        input.interact_requested = true;
+    }
 }
 
-/// System that handles player movement physics based on input resource
-///
-/// Runs in Movement set after grounded check. Handles all physics calculations
-/// and movement execution separately from input detection.
-pub fn apply_player_movement(
+/// Moves the player along the ground in the Idle, Run and Land states.
+pub fn apply_ground_movement(
+    time: Res<Time>,
+    tuning: Res<PlayerTuning>,
+    input: Res<PlayerInput>,
+    mut player: Query<
+        (
+            &PlayerStateMachine,
+            &mut LinearVelocity,
+            &mut GravityScale,
+            (&Speed, &mut RunVelocity),
+            (Has<WallContactLeft>, Has<WallContactRight>),
+            Option<&GroundHit>,
+            Option<&PlatformVelocity>,
+            Option<&SurfaceVelocity>,
+            Option<&Swimming>,
+        ),
+        With<Player>,
+    >,
+    mut movement_events: MessageWriter<PlayerMovement>,
+) {
+    for (
+        machine,
+        mut velocity,
+        mut gravity_scale,
+        (speed, mut run),
+        (wall_left, wall_right),
+        ground,
+        platform_vel,
+        surface_vel,
+        swimming,
+    ) in &mut player
+    {
+        if machine.current.group() != PlayerStateGroup::Grounded {
+            continue;
+        }
+
+        // Platform the player is riding this tick (already moved this tick, so exact)
+        let carry = platform_vel.map_or(Vec2::ZERO, |p| p.current);
+
+        // Moving away from the ground faster than last tick's carry explains, e.g.
+        // rising through a one-way platform or launched by a bounce pad. Comparing
+        // against this tick's carry would mistake a platform that descends or
+        // reverses under the rider for the rider leaving it
+        let carried = platform_vel.map_or(Vec2::ZERO, |p| p.previous);
+        let leaving_ground = ground.is_some_and(|g| (velocity.0 - carried).dot(g.normal) > 1.0);
+
+        // Suspend gravity while standing, so the player neither slides down slopes
+        // nor loses contact with platforms that reverse or descend quickly
+        let scale = match swimming {
+            _ if !leaving_ground => 0.0,
+            Some(swimming) => swimming.gravity_scale,
+            None => 1.0,
+        };
+        if gravity_scale.0 != scale {
+            gravity_scale.0 = scale;
+        }
+
+        run.0 = if is_blocked(input.movement_direction, wall_left, wall_right, 0.0) {
+            0.0
+        } else {
+            run_velocity(
+                run.0,
+                input.movement_direction,
+                speed.0,
+                true,
+                &tuning,
+                time.delta_secs(),
+            )
+        };
+        let surface_x = surface_vel.map(|s| s.0).unwrap_or(0.0);
+
+        match ground.filter(|_| !leaving_ground) {
+            Some(ground) => {
+                // Walk along the ground, ride the platform exactly, and stay snapped
+                // to the ground when it drops away beneath the player
+                velocity.0 = slope_velocity(ground.normal, run.0 + surface_x) + carry;
+                if ground.distance > GROUND_SNAP_GAP && time.delta_secs() > 0.0 {
+                    velocity.y -= (ground.distance - GROUND_SNAP_GAP) / time.delta_secs();
+                }
+            }
+            None => {
+                velocity.x = run.0 + carry.x + surface_x;
+                if let Some(swimming) = swimming {
+                    apply_water_drag(&mut velocity, swimming, time.delta_secs());
+                }
+            }
+        }
+
+        // Send movement event for sprite flipping
+        movement_events.write(PlayerMovement {
+            is_moving: input.movement_direction != 0.0,
+            facing_left: input.movement_direction < 0.0,
+        });
+    }
+}
+
+/// Moves the player through the air in the Jump, Rise, Fall and Flip states, and
+/// through water in the Swim state.
+pub fn apply_air_movement(
     mut commands: Commands,
+    time: Res<Time>,
+    tuning: Res<PlayerTuning>,
     mut input: ResMut<PlayerInput>,
     mut player: Query<
         (
             Entity,
+            &PlayerStateMachine,
             &mut LinearVelocity,
-            &Speed,
-            &JumpVelocity,
-            Has<Grounded>,
-            Has<WallContactLeft>,
-            Has<WallContactRight>,
+            &mut GravityScale,
+            (&Speed, &mut RunVelocity),
+            (Has<Grounded>, Has<WallContactLeft>, Has<WallContactRight>),
+            Option<&GroundHit>,
             Option<&PlatformVelocity>,
-            Option<&CoyoteTimer>,
-            Option<&JumpBuffer>,
+            Option<&PlatformMomentum>,
+            Option<&SurfaceVelocity>,
+            Option<&Swimming>,
         ),
-        (With<Player>, Without<DeathTimer>),
+        With<Player>,
     >,
     mut movement_events: MessageWriter<PlayerMovement>,
 ) {
     for (
         entity,
+        machine,
         mut velocity,
-        speed,
-        jump_vel,
-        is_grounded,
-        wall_left,
-        wall_right,
+        mut gravity_scale,
+        (speed, mut run),
+        (is_grounded, wall_left, wall_right),
+        ground,
         platform_vel,
-        coyote,
-        jump_buffer,
+        momentum,
+        surface_vel,
+        swimming,
     ) in &mut player
     {
-        // Can jump if grounded OR within coyote time
-        let can_jump = is_grounded || coyote.is_some();
-
-        // Jump requested now, or buffered from earlier
-        let wants_jump = input.jump_requested || jump_buffer.is_some();
-
-        if wants_jump && can_jump {
-            velocity.y = jump_vel.0;
-            // Consume coyote time and jump buffer
-            commands
-                .entity(entity)
-                .remove::<CoyoteTimer>()
-                .remove::<JumpBuffer>();
-        } else if input.jump_requested && !can_jump {
-            // Pressed jump in air without coyote - start buffer
-            commands.entity(entity).insert(JumpBuffer::default());
+        let swim = machine.current == PlayerState::Swim;
+        if machine.current.group() != PlayerStateGroup::Airborne && !swim {
+            continue;
         }
 
-        // Clear raw input after processing
+        // Jump presses the state machine didn't turn into a jump
+        if input.jump_requested {
+            match swimming.filter(|_| swim) {
+                // Swim stroke, repeatable while in water
+                Some(swimming) => velocity.y = swimming.stroke_velocity,
+                // Falling onto nearby ground: buffer the press for a full jump on landing
+                None => {
+                    commands
+                        .entity(entity)
+                        .insert(JumpBuffer::new(tuning.jump_buffer_time));
+                }
+            }
+        }
         input.jump_requested = false;
 
-        // Handle horizontal movement (blocked by walls)
-        let blocked = (input.movement_direction < 0.0 && wall_left)
-            || (input.movement_direction > 0.0 && wall_right);
+        let scale = swimming.map_or(1.0, |s| s.gravity_scale);
+        if gravity_scale.0 != scale {
+            gravity_scale.0 = scale;
+        }
 
-        // Base velocity from player input
-        let player_vel = if input.movement_direction != 0.0 && !blocked {
-            input.movement_direction * speed.0
+        // Slopes too steep to stand on block movement into them
+        let steep_slope = ground
+            .filter(|_| !is_grounded)
+            .map(|g| g.normal.x)
+            .unwrap_or(0.0);
+        run.0 = if is_blocked(input.movement_direction, wall_left, wall_right, steep_slope) {
+            0.0
         } else {
-            move_toward(velocity.x, 0.0, speed.0 * 0.5)
+            run_velocity(
+                run.0,
+                input.movement_direction,
+                speed.0,
+                is_grounded,
+                &tuning,
+                time.delta_secs(),
+            )
         };
 
-        // Add platform velocity if standing on a moving platform
-        velocity.x = player_vel + platform_vel.map(|p| p.0.x).unwrap_or(0.0);
+        // Keep the horizontal velocity of the platform the player jumped off, and conveyor speed
+        let platform_x = platform_vel
+            .map(|p| p.current.x)
+            .or(momentum.map(|m| m.0))
+            .unwrap_or(0.0);
+        let surface_x = surface_vel.map(|s| s.0).unwrap_or(0.0);
+        velocity.x = run.0 + platform_x + surface_x;
+
+        if let Some(swimming) = swimming {
+            apply_water_drag(&mut velocity, swimming, time.delta_secs());
+        }
 
         // Send movement event for sprite flipping
         movement_events.write(PlayerMovement {
@@ -287,6 +526,133 @@ pub fn apply_player_movement(
     }
 }
 
+/// Holds the player at dash speed with gravity off in the Dash state.
+pub fn apply_dash(
+    mut players: Query<
+        (
+            &PlayerStateMachine,
+            &Dash,
+            &Dashing,
+            &mut LinearVelocity,
+            &mut GravityScale,
+        ),
+        With<Player>,
+    >,
+) {
+    for (machine, dash, dashing, mut velocity, mut gravity_scale) in &mut players {
+        if machine.current != PlayerState::Dash {
+            continue;
+        }
+
+        velocity.0 = Vec2::new(dashing.direction * dash.speed, 0.0);
+        if gravity_scale.0 != 0.0 {
+            gravity_scale.0 = 0.0;
+        }
+    }
+}
+
+/// Ticks the dash in progress; the state machine ends the Dash state once it finishes.
+pub fn tick_dash(time: Res<Time>, mut query: Query<&mut Dashing>) {
+    for mut dashing in &mut query {
+        dashing.timer.tick(time.delta());
+    }
+}
+
+/// Ticks the dash cooldown and removes it when expired.
+pub fn tick_dash_cooldown(
+    mut commands: Commands,
+    time: Res<Time>,
+    mut query: Query<(Entity, &mut DashCooldown)>,
+) {
+    for (entity, mut cooldown) in &mut query {
+        cooldown.0.tick(time.delta());
+        if cooldown.0.just_finished() {
+            commands.entity(entity).remove::<DashCooldown>();
+        }
+    }
+}
+
+/// Moves the player along the ladder in the Climb state.
+pub fn apply_climbing(
+    input: Res<PlayerInput>,
+    mut players: Query<
+        (
+            &PlayerStateMachine,
+            &mut LinearVelocity,
+            &mut GravityScale,
+            &OnLadder,
+        ),
+        With<Player>,
+    >,
+    mut movement_events: MessageWriter<PlayerMovement>,
+) {
+    for (machine, mut velocity, mut gravity_scale, ladder) in &mut players {
+        if machine.current != PlayerState::Climb {
+            continue;
+        }
+
+        if gravity_scale.0 != 0.0 {
+            gravity_scale.0 = 0.0;
+        }
+        velocity.0 = Vec2::new(input.movement_direction, input.vertical_direction) * ladder.speed;
+
+        movement_events.write(PlayerMovement {
+            is_moving: input.movement_direction != 0.0,
+            facing_left: input.movement_direction < 0.0,
+        });
+    }
+}
+
+/// Whether moving in `direction` pushes into a wall, or into a slope too steep to climb
+/// with the given horizontal normal.
+fn is_blocked(direction: f32, wall_left: bool, wall_right: bool, steep_slope: f32) -> bool {
+    (direction < 0.0 && (wall_left || steep_slope > 0.0))
+        || (direction > 0.0 && (wall_right || steep_slope < 0.0))
+}
+
+/// Water slows everything down and caps the sinking speed.
+fn apply_water_drag(velocity: &mut LinearVelocity, swimming: &Swimming, dt: f32) {
+    velocity.0 /= 1.0 + swimming.drag * dt;
+    velocity.y = velocity.y.max(-swimming.max_fall_speed);
+}
+
+/// Velocity along the ground with the given normal, covering `horizontal` px/s
+/// of walking speed along the surface.
+fn slope_velocity(normal: Vec2, horizontal: f32) -> Vec2 {
+    let tangent = Vec2::new(normal.y, -normal.x).normalize_or(Vec2::X);
+    tangent * horizontal
+}
+
+/// Accelerates `current` toward `direction * speed`, or decelerates it to rest without input.
+/// Reversing direction gets `turn_boost`; in the air only `air_control` of the
+/// acceleration applies.
+fn run_velocity(
+    current: f32,
+    direction: f32,
+    speed: f32,
+    grounded: bool,
+    tuning: &PlayerTuning,
+    dt: f32,
+) -> f32 {
+    if direction == 0.0 {
+        let deceleration = if grounded {
+            tuning.ground_deceleration
+        } else {
+            tuning.air_deceleration
+        };
+        return move_toward(current, 0.0, deceleration * dt);
+    }
+
+    let mut acceleration = tuning.ground_acceleration;
+    if current != 0.0 && current.signum() != direction.signum() {
+        acceleration *= tuning.turn_boost;
+    }
+    if !grounded {
+        acceleration *= tuning.air_control;
+    }
+    move_toward(current, direction * speed, acceleration * dt)
+}
+
 fn move_toward(current: f32, target: f32, max_delta: f32) -> f32 {
     if (target - current).abs() <= max_delta {
         target
@@ -310,39 +676,427 @@ pub fn flip_player_sprite(
     }
 }
 
-/// Updates PlayerAnimation based on grounded state and movement.
-pub fn update_player_animation(
-    mut player: Query<(&mut PlayerAnimation, &LinearVelocity, Has<Grounded>), With<Player>>,
-) {
-    for (mut anim, velocity, is_grounded) in &mut player {
-        let new_anim = if !is_grounded {
-            PlayerAnimation::Jump
-        } else if velocity.x.abs() > 1.0 {
-            PlayerAnimation::Run
+/// Seconds the Land state lasts before settling into Idle
+const LAND_TIME: f32 = 0.1;
+
+/// What the state machine transitions on, gathered from input, physics and ability components.
+struct StateInputs {
+    grounded: bool,
+    /// The player's own run speed, excluding platform and conveyor carry
+    running: bool,
+    swimming: bool,
+    dead: bool,
+    respawning: bool,
+    velocity: Vec2,
+    /// Jump pressed this tick
+    jump_pressed: bool,
+    /// Jump pressed this tick or buffered from earlier
+    wants_jump: bool,
+    /// Grounded, or within coyote time
+    can_jump: bool,
+    /// An air jump is left and no ground is close enough to buffer the press for
+    air_jump: bool,
+    /// Dash pressed, unlocked and ready
+    dash: bool,
+    /// The dash in progress has run its course
+    dash_over: bool,
+    /// Up or down pressed on a ladder, away from that end
+    grab_ladder: bool,
+    /// On a ladder, and not stepping off its bottom
+    on_ladder: bool,
+}
+
+/// Picks the player's next state. New moves add a state here and to `PlayerState`,
+/// an enter hook launching the move, and a movement system running in that state.
+fn next_player_state(machine: &PlayerStateMachine, inputs: &StateInputs) -> PlayerState {
+    // Special states override everything else, in priority order
+    if inputs.dead {
+        return PlayerState::Dead;
+    }
+    if inputs.respawning {
+        return PlayerState::Respawn;
+    }
+    if machine.current == PlayerState::Dash && !inputs.dash_over {
+        return PlayerState::Dash;
+    }
+
+    let climbing = machine.current == PlayerState::Climb;
+    if inputs.dash && !climbing {
+        return PlayerState::Dash;
+    }
+    if climbing {
+        if inputs.jump_pressed {
+            return PlayerState::Jump;
+        }
+        if inputs.on_ladder {
+            return PlayerState::Climb;
+        }
+    } else if inputs.grab_ladder {
+        return PlayerState::Climb;
+    }
+
+    if inputs.wants_jump && inputs.can_jump {
+        return PlayerState::Jump;
+    }
+    if inputs.swimming && !inputs.grounded {
+        return PlayerState::Swim;
+    }
+    if inputs.jump_pressed && inputs.air_jump {
+        return PlayerState::Flip;
+    }
+
+    if inputs.grounded {
+        let landing = machine.current.group() == PlayerStateGroup::Airborne
+            || (machine.current == PlayerState::Land && machine.elapsed < LAND_TIME);
+        return if inputs.running {
+            PlayerState::Run
+        } else if landing {
+            PlayerState::Land
         } else {
-            PlayerAnimation::Idle
+            PlayerState::Idle
         };
+    }
+
+    // The somersault lasts until landing
+    if machine.current == PlayerState::Flip {
+        PlayerState::Flip
+    } else if inputs.velocity.y > 0.0 {
+        PlayerState::Rise
+    } else {
+        PlayerState::Fall
+    }
+}
+
+/// Advances the player's state machine before anything moves, announcing transitions
+/// with a `PlayerStateChanged` message and `ExitPlayerState`/`EnterPlayerState` events.
+pub fn update_player_state(
+    mut commands: Commands,
+    time: Res<Time>,
+    mut input: ResMut<PlayerInput>,
+    unlocked: Res<UnlockedAbilities>,
+    spatial_query: SpatialQuery,
+    sensors: Query<(), With<Sensor>>,
+    mut movement_events: MessageReader<PlayerMovement>,
+    mut player: Query<
+        (
+            Entity,
+            &mut PlayerStateMachine,
+            &Position,
+            &LinearVelocity,
+            (&RunVelocity, &AirJumps, &mut Dash),
+            (Option<&Dashing>, Option<&OnLadder>),
+            (
+                Has<Grounded>,
+                Has<Swimming>,
+                Has<CoyoteTimer>,
+                Has<JumpBuffer>,
+            ),
+            (Has<AirDashUsed>, Has<DashCooldown>),
+            (Has<DeathTimer>, Has<Respawning>),
+        ),
+        With<Player>,
+    >,
+    mut state_changes: MessageWriter<PlayerStateChanged>,
+) {
+    let dash_requested = input.dash_requested;
+    input.dash_requested = false;
+
+    // Last direction the player moved in, which dashes go in
+    let facing_left = movement_events
+        .read()
+        .filter(|event| event.is_moving)
+        .last()
+        .map(|event| event.facing_left);
+
+    for (
+        entity,
+        mut machine,
+        position,
+        velocity,
+        (run, air_jumps, mut dash),
+        (dashing, ladder),
+        (grounded, swimming, coyote, jump_buffer),
+        (air_dash_used, cooling_down),
+        (dead, respawning),
+    ) in &mut player
+    {
+        if let Some(facing_left) = facing_left {
+            dash.facing_left = facing_left;
+        }
+
+        let can_jump = grounded || coyote;
+
+        // Falling onto nearby ground: a jump press is buffered for a full jump on landing
+        // rather than spending an air jump on it
+        let near_ground = input.jump_requested
+            && !can_jump
+            && velocity.y <= 0.0
+            && spatial_query
+                .cast_ray_predicate(
+                    position.0,
+                    Dir2::NEG_Y,
+                    PLAYER_HALF_HEIGHT + AIR_JUMP_GROUND_MARGIN,
+                    true,
+                    &SpatialQueryFilter::default().with_excluded_entities([entity]),
+                    &|hit| !sensors.contains(hit),
+                )
+                .is_some();
+
+        let inputs = StateInputs {
+            grounded,
+            running: run.0.abs() > 1.0,
+            swimming,
+            dead,
+            respawning,
+            velocity: velocity.0,
+            jump_pressed: input.jump_requested,
+            wants_jump: input.jump_requested || jump_buffer,
+            can_jump,
+            air_jump: air_jumps.remaining > 0 && !near_ground,
+            dash: dash_requested
+                && !cooling_down
+                && (grounded || !air_dash_used)
+                && unlocked.has(Ability::Dash),
+            dash_over: dashing.is_none_or(|d| d.timer.is_finished()),
+            grab_ladder: ladder.is_some_and(|l| {
+                (input.vertical_direction > 0.0 && !l.at_top)
+                    || (input.vertical_direction < 0.0 && !l.at_bottom)
+            }),
+            on_ladder: ladder.is_some_and(|l| !(l.at_bottom && input.vertical_direction < 0.0)),
+        };
+
+        let next = next_player_state(&machine, &inputs);
+
+        // Jumps re-enter their state, so a second air jump mid-somersault launches too
+        let launched = match next {
+            PlayerState::Jump => true,
+            PlayerState::Flip => inputs.jump_pressed && inputs.air_jump,
+            _ => false,
+        };
+        if launched {
+            input.jump_requested = false;
+        }
+        if next == machine.current && !launched {
+            machine.elapsed += time.delta_secs();
+            continue;
+        }
+
+        let from = machine.current;
+        machine.previous = from;
+        machine.current = next;
+        machine.elapsed = 0.0;
 
+        state_changes.write(PlayerStateChanged {
+            player: entity,
+            from,
+            to: next,
+        });
+        commands.trigger(ExitPlayerState {
+            entity,
+            state: from,
+        });
+        commands.trigger(EnterPlayerState {
+            entity,
+            state: next,
+        });
+    }
+}
+
+/// Entering Jump launches the player, carrying the vertical velocity of the platform
+/// they jumped off.
+pub fn launch_jump(
+    enter: On<EnterPlayerState>,
+    mut commands: Commands,
+    mut players: Query<(
+        &mut LinearVelocity,
+        &JumpVelocity,
+        Option<&PlatformVelocity>,
+        Has<Grounded>,
+    )>,
+) {
+    if enter.state != PlayerState::Jump {
+        return;
+    }
+    if let Ok((mut velocity, jump_vel, platform_vel, grounded)) = players.get_mut(enter.entity) {
+        let carry = platform_vel
+            .filter(|_| grounded)
+            .map_or(0.0, |p| p.current.y);
+        velocity.y = jump_vel.0 + carry;
+    }
+    // Consume coyote time and jump buffer
+    commands
+        .entity(enter.entity)
+        .remove::<(CoyoteTimer, JumpBuffer)>();
+}
+
+/// Entering Flip spends an air jump.
+pub fn launch_air_jump(
+    enter: On<EnterPlayerState>,
+    mut commands: Commands,
+    mut players: Query<(&mut LinearVelocity, &mut AirJumps)>,
+) {
+    if enter.state != PlayerState::Flip {
+        return;
+    }
+    if let Ok((mut velocity, mut air_jumps)) = players.get_mut(enter.entity) {
+        air_jumps.remaining = air_jumps.remaining.saturating_sub(1);
+        velocity.y = air_jumps.velocity;
+    }
+    commands.entity(enter.entity).remove::<JumpBuffer>();
+}
+
+/// Entering Dash starts a dash in the facing direction. Only one dash is allowed per airtime.
+pub fn start_dash(
+    enter: On<EnterPlayerState>,
+    mut commands: Commands,
+    players: Query<(&Dash, Has<Grounded>)>,
+) {
+    if enter.state != PlayerState::Dash {
+        return;
+    }
+    let Ok((dash, grounded)) = players.get(enter.entity) else {
+        return;
+    };
+
+    let mut player = commands.entity(enter.entity);
+    player.insert((
+        Dashing {
+            timer: Timer::from_seconds(dash.duration, TimerMode::Once),
+            direction: if dash.facing_left { -1.0 } else { 1.0 },
+            through_enemies: dash.through_enemies,
+        },
+        DashCooldown(Timer::from_seconds(dash.cooldown, TimerMode::Once)),
+    ));
+    if !grounded {
+        player.insert(AirDashUsed);
+    }
+}
+
+/// Leaving Dash drops back to run speed.
+pub fn end_dash(
+    exit: On<ExitPlayerState>,
+    mut commands: Commands,
+    mut players: Query<(&Dash, &mut LinearVelocity, &mut RunVelocity)>,
+) {
+    if exit.state != PlayerState::Dash {
+        return;
+    }
+    if let Ok((dash, mut velocity, mut run)) = players.get_mut(exit.entity) {
+        velocity.x = velocity.x.clamp(-dash.speed * 0.4, dash.speed * 0.4);
+        run.0 = velocity.x;
+    }
+    commands.entity(exit.entity).remove::<Dashing>();
+}
+
+/// Entering Climb grabs the ladder, stopping the player and dropping any pending jump.
+pub fn grab_ladder(
+    enter: On<EnterPlayerState>,
+    mut commands: Commands,
+    mut players: Query<(&mut LinearVelocity, &mut RunVelocity)>,
+) {
+    if enter.state != PlayerState::Climb {
+        return;
+    }
+    if let Ok((mut velocity, mut run)) = players.get_mut(enter.entity) {
+        velocity.0 = Vec2::ZERO;
+        run.0 = 0.0;
+    }
+    commands
+        .entity(enter.entity)
+        .remove::<(CoyoteTimer, JumpBuffer)>();
+}
+
+/// Entering a grounded state refills the air jumps and the air dash.
+pub fn refill_air_moves_on_land(
+    enter: On<EnterPlayerState>,
+    mut commands: Commands,
+    mut players: Query<&mut AirJumps>,
+) {
+    if enter.state.group() != PlayerStateGroup::Grounded {
+        return;
+    }
+    if let Ok(mut air_jumps) = players.get_mut(enter.entity) {
+        let max = air_jumps.max();
+        if air_jumps.remaining != max {
+            air_jumps.remaining = max;
+        }
+    }
+    commands.entity(enter.entity).remove::<AirDashUsed>();
+}
+
+/// Projects the player's state onto its animation.
+pub fn update_player_animation(
+    mut player: Query<(&PlayerStateMachine, &mut PlayerAnimation), With<Player>>,
+) {
+    for (machine, mut anim) in &mut player {
+        let new_anim = machine.current.animation();
         if *anim != new_anim {
             *anim = new_anim;
         }
     }
 }
 
-/// Syncs SpriteAnimation when PlayerAnimation changes.
+/// Switches the knight sheet animation when PlayerAnimation changes.
 pub fn sync_player_animation(
     mut commands: Commands,
-    mut player: Query<(Entity, &PlayerAnimation, &mut Sprite), Changed<PlayerAnimation>>,
+    atlas: Res<KnightAtlas>,
+    player: Query<(Entity, &PlayerAnimation), Changed<PlayerAnimation>>,
 ) {
-    for (entity, anim, mut sprite) in &mut player {
-        let (first, last) = anim.frames();
+    for (entity, anim) in &player {
         commands
             .entity(entity)
-            .insert(SpriteAnimation::new(first, last, 10));
+            .insert(anim.aseprite_animation(atlas.sheet.clone()));
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// One eighth of a second, so every velocity change below is exact
+    const DT: f32 = 0.125;
 
-        // Reset sprite to first frame of new animation
-        if let Some(atlas) = &mut sprite.texture_atlas {
-            atlas.index = first;
+    fn tuning() -> PlayerTuning {
+        PlayerTuning {
+            ground_acceleration: 800.0,
+            ground_deceleration: 400.0,
+            turn_boost: 2.0,
+            air_control: 0.5,
+            air_deceleration: 80.0,
+            ..default()
         }
     }
+
+    #[test]
+    fn run_velocity_accelerates_up_to_speed() {
+        let tuning = tuning();
+
+        assert_eq!(run_velocity(0.0, 1.0, 130.0, true, &tuning, DT), 100.0);
+        assert_eq!(run_velocity(100.0, 1.0, 130.0, true, &tuning, DT), 130.0);
+        assert_eq!(run_velocity(0.0, -1.0, 130.0, true, &tuning, DT), -100.0);
+    }
+
+    #[test]
+    fn run_velocity_turns_around_with_turn_boost() {
+        let tuning = tuning();
+
+        assert_eq!(run_velocity(130.0, -1.0, 130.0, true, &tuning, DT), -70.0);
+    }
+
+    #[test]
+    fn run_velocity_scales_air_acceleration_by_air_control() {
+        let tuning = tuning();
+
+        assert_eq!(run_velocity(0.0, 1.0, 130.0, false, &tuning, DT), 50.0);
+        assert_eq!(run_velocity(130.0, -1.0, 130.0, false, &tuning, DT), 30.0);
+    }
+
+    #[test]
+    fn run_velocity_decelerates_to_rest_without_input() {
+        let tuning = tuning();
+
+        assert_eq!(run_velocity(100.0, 0.0, 130.0, true, &tuning, DT), 50.0);
+        assert_eq!(run_velocity(-30.0, 0.0, 130.0, true, &tuning, DT), 0.0);
+        assert_eq!(run_velocity(100.0, 0.0, 130.0, false, &tuning, DT), 90.0);
+    }
 }
@@ -0,0 +1,248 @@
+//! Headless regression tests for riding kinematic moving platforms.
+
+use super::components::{
+    GroundHit, Grounded, PLAYER_HALF_HEIGHT, PlatformMomentum, PlatformVelocity, Player, StandingOn,
+};
+use super::messages::{PlayerMovement, PlayerStateChanged};
+use super::resources::{KnightAtlas, PlayerInput};
+use super::systems::{
+    GROUND_SNAP_GAP, apply_air_movement, apply_ground_movement, launch_jump,
+    refill_air_moves_on_land, update_grounded, update_platform_velocity, update_player_state,
+};
+use super::tuning::PlayerTuning;
+use crate::abilities::resources::UnlockedAbilities;
+use crate::platform::{MovingPlatform, PlatformHooks, PlatformTexture, update_moving_platforms};
+use avian2d::prelude::*;
+use bevy::{prelude::*, scene::ScenePlugin, time::TimeUpdateStrategy};
+use std::time::Duration;
+
+/// One fixed tick per `App::update`, at Bevy's default fixed rate
+const TICK: f32 = 1.0 / 64.0;
+
+/// Half the height of the 32x9 platform collider
+const PLATFORM_HALF_HEIGHT: f32 = 4.5;
+
+/// Ticks for physics to produce the first ground hits before measuring
+const SETTLE_TICKS: usize = 4;
+
+/// Largest drift in pixels allowed between rider and platform
+const TOLERANCE: f32 = 0.25;
+
+fn test_app() -> App {
+    let mut app = App::new();
+    app.add_plugins((
+        MinimalPlugins,
+        TransformPlugin,
+        AssetPlugin::default(),
+        // Avian's collider constructor systems read the scene spawner
+        ScenePlugin,
+        PhysicsPlugins::default()
+            .with_length_unit(16.0)
+            .with_collision_hooks::<PlatformHooks>(),
+    ))
+    .init_asset::<Mesh>()
+    .insert_resource(TimeUpdateStrategy::ManualDuration(Duration::from_secs_f32(
+        TICK,
+    )))
+    .insert_resource(Gravity(Vec2::new(0.0, PlayerTuning::default().gravity)))
+    .init_resource::<PlayerTuning>()
+    .init_resource::<PlayerInput>()
+    .init_resource::<KnightAtlas>()
+    .init_resource::<PlatformTexture>()
+    .init_resource::<UnlockedAbilities>()
+    .add_message::<PlayerMovement>()
+    .add_message::<PlayerStateChanged>()
+    // Same order as the platform and player plugins
+    .add_systems(
+        FixedUpdate,
+        (
+            update_moving_platforms,
+            (update_grounded, update_platform_velocity),
+            update_player_state,
+            (apply_ground_movement, apply_air_movement),
+        )
+            .chain(),
+    )
+    .add_observer(launch_jump)
+    .add_observer(refill_air_moves_on_land);
+    app.finish();
+    app.cleanup();
+    app
+}
+
+/// Spawns a platform travelling back and forth between `from` and `to` (its center).
+fn spawn_platform(app: &mut App, from: Vec2, to: Vec2, speed: f32) -> Entity {
+    let mut platform = MovingPlatform {
+        speed,
+        waypoints: vec![from, to],
+        waits: vec![0.0, 0.0],
+        ..default()
+    };
+    let start = platform.seek(0.0);
+    app.world_mut()
+        .spawn((platform, Transform::from_translation(start.extend(0.0))))
+        .id()
+}
+
+/// Spawns the player standing `gap` pixels above the top of the platform centered at `center`.
+fn spawn_rider(app: &mut App, center: Vec2, gap: f32) -> Entity {
+    let feet = center.y + PLATFORM_HALF_HEIGHT + gap;
+    app.world_mut()
+        .spawn((
+            Player,
+            Transform::from_xyz(center.x, feet + PLAYER_HALF_HEIGHT, 1.0),
+        ))
+        .id()
+}
+
+fn step(app: &mut App, ticks: usize) {
+    for _ in 0..ticks {
+        app.update();
+    }
+}
+
+fn position(app: &App, entity: Entity) -> Vec2 {
+    app.world().get::<Position>(entity).unwrap().0
+}
+
+fn offset(app: &App, rider: Entity, platform: Entity) -> Vec2 {
+    position(app, rider) - position(app, platform)
+}
+
+/// Steps `ticks` fixed ticks, checking every tick that the rider stays grounded,
+/// doesn't fall under gravity, and keeps its offset from the platform.
+/// Returns the platform's velocity on each tick.
+fn assert_rides(app: &mut App, rider: Entity, platform: Entity, ticks: usize) -> Vec<Vec2> {
+    step(app, SETTLE_TICKS);
+    let start = offset(app, rider, platform);
+    let mut velocities = Vec::with_capacity(ticks);
+
+    for tick in 0..ticks {
+        step(app, 1);
+        let world = app.world();
+        assert!(
+            world.get::<Grounded>(rider).is_some(),
+            "rider lost Grounded on tick {tick}"
+        );
+        assert_eq!(
+            world.get::<GravityScale>(rider).unwrap().0,
+            0.0,
+            "gravity not suspended on tick {tick}"
+        );
+        let drift = offset(app, rider, platform) - start;
+        assert!(
+            drift.length() < TOLERANCE,
+            "rider drifted {drift} from the platform on tick {tick}"
+        );
+        velocities.push(world.get::<LinearVelocity>(platform).unwrap().0);
+    }
+    velocities
+}
+
+#[test]
+fn rides_platform_moving_up() {
+    let mut app = test_app();
+    let platform = spawn_platform(&mut app, Vec2::ZERO, Vec2::new(0.0, 200.0), 60.0);
+    let rider = spawn_rider(&mut app, Vec2::ZERO, 0.0);
+
+    assert_rides(&mut app, rider, platform, 120);
+}
+
+#[test]
+fn rides_platform_moving_down() {
+    let mut app = test_app();
+    let platform = spawn_platform(&mut app, Vec2::ZERO, Vec2::new(0.0, -200.0), 60.0);
+    let rider = spawn_rider(&mut app, Vec2::ZERO, 0.0);
+
+    assert_rides(&mut app, rider, platform, 120);
+}
+
+#[test]
+fn rides_platform_moving_diagonally() {
+    let mut app = test_app();
+    let platform = spawn_platform(&mut app, Vec2::ZERO, Vec2::new(150.0, -150.0), 60.0);
+    let rider = spawn_rider(&mut app, Vec2::ZERO, 0.0);
+
+    assert_rides(&mut app, rider, platform, 120);
+}
+
+#[test]
+fn rides_platform_through_reversals() {
+    let mut app = test_app();
+    // 32 pixels at 80 px/s reverses roughly every 26 ticks
+    let platform = spawn_platform(&mut app, Vec2::ZERO, Vec2::new(0.0, 32.0), 80.0);
+    let rider = spawn_rider(&mut app, Vec2::ZERO, 0.0);
+
+    let velocities = assert_rides(&mut app, rider, platform, 120);
+
+    assert!(velocities.iter().any(|v| v.y > 0.0));
+    assert!(
+        velocities.iter().any(|v| v.y < 0.0),
+        "platform never reversed"
+    );
+}
+
+#[test]
+fn snaps_down_onto_platform_within_reach() {
+    let mut app = test_app();
+    let platform = spawn_platform(&mut app, Vec2::ZERO, Vec2::new(0.0, -200.0), 60.0);
+    // Close enough to stay within the ground cast after the platform's first step down
+    let rider = spawn_rider(&mut app, Vec2::ZERO, 0.75);
+
+    step(&mut app, SETTLE_TICKS);
+
+    let world = app.world();
+    assert!(world.get::<Grounded>(rider).is_some());
+    let ground = world.get::<GroundHit>(rider).unwrap();
+    assert!(
+        ground.distance <= GROUND_SNAP_GAP + TOLERANCE,
+        "rider hovering {} above the platform",
+        ground.distance
+    );
+    assert_eq!(world.get::<StandingOn>(rider).unwrap().0, platform);
+}
+
+#[test]
+fn jump_inherits_vertical_platform_velocity() {
+    let mut app = test_app();
+    spawn_platform(&mut app, Vec2::ZERO, Vec2::new(0.0, 200.0), 60.0);
+    let rider = spawn_rider(&mut app, Vec2::ZERO, 0.0);
+    step(&mut app, SETTLE_TICKS);
+
+    let carry = app.world().get::<PlatformVelocity>(rider).unwrap().current;
+    assert!(carry.y > 0.0, "platform not moving up: {carry}");
+
+    app.world_mut().resource_mut::<PlayerInput>().jump_requested = true;
+    step(&mut app, 1);
+
+    // Jump velocity plus the carry, less one tick of gravity
+    let tuning = PlayerTuning::default();
+    let expected = tuning.jump_velocity + carry.y + tuning.gravity * TICK;
+    let velocity = app.world().get::<LinearVelocity>(rider).unwrap().y;
+    assert!(
+        (velocity - expected).abs() < 1.0,
+        "jumped at {velocity}, expected {expected}"
+    );
+}
+
+#[test]
+fn keeps_horizontal_momentum_after_jumping_off() {
+    let mut app = test_app();
+    let platform = spawn_platform(&mut app, Vec2::ZERO, Vec2::new(400.0, 0.0), 60.0);
+    let rider = spawn_rider(&mut app, Vec2::ZERO, 0.0);
+    step(&mut app, SETTLE_TICKS);
+
+    app.world_mut().resource_mut::<PlayerInput>().jump_requested = true;
+    step(&mut app, 4);
+
+    let world = app.world();
+    assert!(world.get::<Grounded>(rider).is_none());
+    let momentum = world.get::<PlatformMomentum>(rider).unwrap().0;
+    let platform_x = world.get::<LinearVelocity>(platform).unwrap().x;
+    assert!((momentum - platform_x).abs() < 0.01);
+    let velocity = world.get::<LinearVelocity>(rider).unwrap().x;
+    assert!(
+        (velocity - momentum).abs() < 0.01,
+        "rider moving at {velocity} in the air, momentum {momentum}"
+    );
+}
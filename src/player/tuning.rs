@@ -0,0 +1,103 @@
+use bevy::{
+    asset::{AssetLoader, LoadContext, io::Reader},
+    prelude::*,
+};
+use serde::Deserialize;
+
+/// Player feel parameters, loaded from `assets/tuning/player.tuning.ron` and
+/// hot-reloaded while the game runs (with the `debug` feature's file watcher).
+///
+/// The asset is copied into the `PlayerTuning` resource, which is what systems read.
+/// Defaults apply until the file has loaded.
+#[derive(Asset, Resource, Reflect, Deserialize, Debug, Clone)]
+#[reflect(Resource)]
+#[serde(default)]
+pub struct PlayerTuning {
+    /// Horizontal run speed in pixels per second
+    pub run_speed: f32,
+    /// Upward velocity of a grounded jump
+    pub jump_velocity: f32,
+    /// Seconds a jump is still allowed after walking off a ledge
+    pub coyote_time: f32,
+    /// Seconds a jump pressed before landing is remembered
+    pub jump_buffer_time: f32,
+    /// Steepest walkable slope in degrees
+    pub max_slope_angle: f32,
+    /// Horizontal acceleration on the ground in pixels per second squared
+    pub ground_acceleration: f32,
+    /// Horizontal deceleration on the ground without input, in pixels per second squared
+    pub ground_deceleration: f32,
+    /// Acceleration multiplier when pressing against the current direction of travel
+    pub turn_boost: f32,
+    /// Fraction (0..1) of ground acceleration available in the air
+    pub air_control: f32,
+    /// Horizontal deceleration in the air without input, in pixels per second squared
+    pub air_deceleration: f32,
+    /// World gravity in pixels per second squared (negative = down)
+    pub gravity: f32,
+    /// Game speed while the player dies, as a fraction of normal speed
+    pub death_time_scale: f32,
+    /// Upward velocity the player pops up with on death
+    pub death_pop_velocity: f32,
+    /// Seconds of (slowed) game time the death plays before the screen fades
+    pub death_hold_time: f32,
+    /// Seconds the screen takes to fade to black before the level reloads
+    pub fade_out_time: f32,
+    /// Seconds the screen takes to fade back in once the player has respawned
+    pub fade_in_time: f32,
+    /// Seconds the player holds the respawn pose before control returns
+    pub respawn_pose_time: f32,
+}
+
+impl Default for PlayerTuning {
+    fn default() -> Self {
+        Self {
+            run_speed: 130.0,
+            jump_velocity: 300.0,
+            coyote_time: 0.1,
+            jump_buffer_time: 0.1,
+            max_slope_angle: 45.0,
+            ground_acceleration: 1500.0,
+            ground_deceleration: 1800.0,
+            turn_boost: 2.0,
+            air_control: 0.65,
+            air_deceleration: 400.0,
+            gravity: -980.0,
+            death_time_scale: 0.5,
+            death_pop_velocity: 200.0,
+            death_hold_time: 0.6,
+            fade_out_time: 0.35,
+            fade_in_time: 0.35,
+            respawn_pose_time: 0.4,
+        }
+    }
+}
+
+/// Handle keeping the tuning asset loaded and identifying its reload events.
+#[derive(Resource)]
+pub struct PlayerTuningHandle(pub Handle<PlayerTuning>);
+
+/// Loads `.tuning.ron` files as `PlayerTuning`.
+#[derive(Default, TypePath)]
+pub struct PlayerTuningLoader;
+
+impl AssetLoader for PlayerTuningLoader {
+    type Asset = PlayerTuning;
+    type Settings = ();
+    type Error = BevyError;
+
+    async fn load(
+        &self,
+        reader: &mut dyn Reader,
+        _settings: &(),
+        _load_context: &mut LoadContext<'_>,
+    ) -> Result<Self::Asset, Self::Error> {
+        let mut bytes = Vec::new();
+        reader.read_to_end(&mut bytes).await?;
+        Ok(ron::de::from_bytes(&bytes)?)
+    }
+
+    fn extensions(&self) -> &[&str] {
+        &["tuning.ron"]
+    }
+}
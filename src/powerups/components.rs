@@ -0,0 +1,163 @@
+use crate::core::components::Speed;
+use crate::pickups::components::{Pickup, PickupKind};
+use crate::player::{AirJumps, Grounded, JumpVelocity};
+use bevy::{
+    ecs::{lifecycle::HookContext, world::DeferredWorld},
+    prelude::*,
+};
+
+/// The effect a power-up applies to the player.
+#[derive(Reflect, Default, Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum PowerUpKind {
+    /// Multiplies `Speed` by `strength`.
+    #[default]
+    Speed,
+    /// Multiplies `JumpVelocity` by `strength`.
+    Jump,
+    /// Ignores enemy contact.
+    Invulnerable,
+    /// Pulls coins within `strength` pixels toward the player.
+    Magnet,
+    /// Grants `strength` (rounded down) extra air jumps.
+    AirJump,
+}
+
+/// What happens when a power-up of an already active kind is collected.
+#[derive(Reflect, Default, Debug, Clone, Copy, PartialEq, Eq)]
+pub enum PowerUpStacking {
+    /// Restart the timer at the full duration.
+    #[default]
+    Refresh,
+    /// Add the duration to the remaining time.
+    Extend,
+}
+
+/// Fruit pickup placed in Tiled, granting a timed effect.
+#[derive(Component, Reflect, Debug, Clone)]
+#[reflect(Component, Default)]
+#[require(Pickup = Pickup { kind: PickupKind::PowerUp, value: 0, respawn_time: 0.0 })]
+pub struct PowerUp {
+    pub kind: PowerUpKind,
+    /// Effect duration in seconds
+    pub duration: f32,
+    /// Multiplier for Speed/Jump, radius for Magnet, extra jumps for AirJump,
+    /// unused for Invulnerable
+    pub strength: f32,
+    pub stacking: PowerUpStacking,
+}
+
+impl Default for PowerUp {
+    fn default() -> Self {
+        Self {
+            kind: PowerUpKind::Speed,
+            duration: 5.0,
+            strength: 1.5,
+            stacking: PowerUpStacking::Refresh,
+        }
+    }
+}
+
+/// A running power-up effect on the player.
+#[derive(Reflect, Debug, Clone)]
+pub struct ActivePowerUp {
+    pub kind: PowerUpKind,
+    pub timer: Timer,
+}
+
+/// All power-up effects currently running on the player.
+#[derive(Component, Reflect, Default)]
+#[reflect(Component)]
+pub struct ActivePowerUps(pub Vec<ActivePowerUp>);
+
+/// Multiplies `Speed` while present, restoring it when removed.
+#[derive(Component, Reflect)]
+#[reflect(Component)]
+#[component(on_insert = Self::on_insert, on_replace = Self::on_replace)]
+pub struct SpeedBoost(pub f32);
+
+impl SpeedBoost {
+    fn on_insert(mut world: DeferredWorld, ctx: HookContext) {
+        let multiplier = world.get::<Self>(ctx.entity).map_or(1.0, |b| b.0);
+        if let Some(mut speed) = world.get_mut::<Speed>(ctx.entity) {
+            speed.0 *= multiplier;
+        }
+    }
+
+    fn on_replace(mut world: DeferredWorld, ctx: HookContext) {
+        let multiplier = world.get::<Self>(ctx.entity).map_or(1.0, |b| b.0);
+        if let Some(mut speed) = world.get_mut::<Speed>(ctx.entity) {
+            speed.0 /= multiplier;
+        }
+    }
+}
+
+/// Multiplies `JumpVelocity` while present, restoring it when removed.
+#[derive(Component, Reflect)]
+#[reflect(Component)]
+#[component(on_insert = Self::on_insert, on_replace = Self::on_replace)]
+pub struct JumpBoost(pub f32);
+
+impl JumpBoost {
+    fn on_insert(mut world: DeferredWorld, ctx: HookContext) {
+        let multiplier = world.get::<Self>(ctx.entity).map_or(1.0, |b| b.0);
+        if let Some(mut jump) = world.get_mut::<JumpVelocity>(ctx.entity) {
+            jump.0 *= multiplier;
+        }
+    }
+
+    fn on_replace(mut world: DeferredWorld, ctx: HookContext) {
+        let multiplier = world.get::<Self>(ctx.entity).map_or(1.0, |b| b.0);
+        if let Some(mut jump) = world.get_mut::<JumpVelocity>(ctx.entity) {
+            jump.0 /= multiplier;
+        }
+    }
+}
+
+/// Adds air jumps while present, taking them away again when removed.
+/// The extra jumps become available on landing, or straight away if picked up on the ground;
+/// collecting it again mid-air doesn't refill them.
+#[derive(Component, Reflect)]
+#[reflect(Component)]
+#[component(on_insert = Self::on_insert, on_replace = Self::on_replace)]
+pub struct ExtraAirJumps(pub u32);
+
+impl ExtraAirJumps {
+    fn on_insert(mut world: DeferredWorld, ctx: HookContext) {
+        let extra = world.get::<Self>(ctx.entity).map_or(0, |e| e.0);
+        let grounded = world.get::<Grounded>(ctx.entity).is_some();
+        if let Some(mut air_jumps) = world.get_mut::<AirJumps>(ctx.entity) {
+            air_jumps.bonus += extra;
+            // Landing refills them, but a player already on the ground has no landing to come
+            air_jumps.remaining = if grounded {
+                air_jumps.max()
+            } else {
+                air_jumps.remaining.min(air_jumps.max())
+            };
+        }
+    }
+
+    fn on_replace(mut world: DeferredWorld, ctx: HookContext) {
+        let extra = world.get::<Self>(ctx.entity).map_or(0, |e| e.0);
+        if let Some(mut air_jumps) = world.get_mut::<AirJumps>(ctx.entity) {
+            air_jumps.bonus = air_jumps.bonus.saturating_sub(extra);
+            air_jumps.remaining = air_jumps.remaining.min(air_jumps.max());
+        }
+    }
+}
+
+/// Player ignores enemy contact while present.
+#[derive(Component, Reflect, Default)]
+#[reflect(Component)]
+pub struct Invulnerable;
+
+/// Pulls coins within `radius` toward the player.
+#[derive(Component, Reflect, Default)]
+#[reflect(Component)]
+pub struct CoinMagnet {
+    pub radius: f32,
+}
+
+/// Marker for the active power-up HUD text.
+#[derive(Component, Reflect, Default)]
+#[reflect(Component)]
+pub struct PowerUpHud;
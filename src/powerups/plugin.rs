@@ -0,0 +1,38 @@
+use super::components::{
+    ActivePowerUps, CoinMagnet, ExtraAirJumps, Invulnerable, JumpBoost, PowerUp, PowerUpHud,
+    SpeedBoost,
+};
+use super::systems::{
+    apply_power_ups, attract_coins, clear_power_ups_on_death, flash_invulnerable_player,
+    spawn_power_up_hud, tick_power_ups, update_power_up_hud,
+};
+use crate::pickups::PickupSystemSet;
+use bevy::prelude::*;
+
+pub struct PowerUpsPlugin;
+
+impl Plugin for PowerUpsPlugin {
+    fn build(&self, app: &mut App) {
+        app.register_type::<PowerUp>()
+            .register_type::<ActivePowerUps>()
+            .register_type::<SpeedBoost>()
+            .register_type::<JumpBoost>()
+            .register_type::<ExtraAirJumps>()
+            .register_type::<Invulnerable>()
+            .register_type::<CoinMagnet>()
+            .register_type::<PowerUpHud>()
+            .add_systems(Startup, spawn_power_up_hud)
+            .add_systems(
+                Update,
+                (
+                    (apply_power_ups, tick_power_ups)
+                        .chain()
+                        .after(PickupSystemSet::Collect),
+                    clear_power_ups_on_death,
+                    attract_coins,
+                    flash_invulnerable_player,
+                    update_power_up_hud,
+                ),
+            );
+    }
+}
@@ -0,0 +1,196 @@
+use super::components::{
+    ActivePowerUp, ActivePowerUps, CoinMagnet, ExtraAirJumps, Invulnerable, JumpBoost, PowerUp,
+    PowerUpHud, PowerUpKind, PowerUpStacking, SpeedBoost,
+};
+use crate::killzone::components::DeathTimer;
+use crate::pickups::components::{Collected, Pickup, PickupKind};
+use crate::pickups::messages::PickupCollected;
+use crate::player::Player;
+use bevy::prelude::*;
+use std::time::Duration;
+
+/// Starts or stacks the collected power-up's effect on the player.
+pub fn apply_power_ups(
+    mut commands: Commands,
+    mut messages: MessageReader<PickupCollected>,
+    power_ups: Query<&PowerUp>,
+    player: Query<(Entity, Option<&ActivePowerUps>), With<Player>>,
+) {
+    let Ok((entity, active)) = player.single() else {
+        return;
+    };
+    if messages.is_empty() {
+        return;
+    }
+
+    let mut effects = active.map(|a| a.0.clone()).unwrap_or_default();
+
+    for evt in messages.read() {
+        let Ok(power_up) = power_ups.get(evt.entity) else {
+            continue;
+        };
+        let duration = Duration::from_secs_f32(power_up.duration);
+
+        if let Some(existing) = effects.iter_mut().find(|p| p.kind == power_up.kind) {
+            let duration = match power_up.stacking {
+                PowerUpStacking::Refresh => duration,
+                PowerUpStacking::Extend => existing.timer.remaining() + duration,
+            };
+            existing.timer = Timer::new(duration, TimerMode::Once);
+            continue;
+        }
+
+        effects.push(ActivePowerUp {
+            kind: power_up.kind,
+            timer: Timer::new(duration, TimerMode::Once),
+        });
+
+        let mut player = commands.entity(entity);
+        match power_up.kind {
+            PowerUpKind::Speed => player.insert(SpeedBoost(power_up.strength)),
+            PowerUpKind::Jump => player.insert(JumpBoost(power_up.strength)),
+            PowerUpKind::Invulnerable => player.insert(Invulnerable),
+            PowerUpKind::Magnet => player.insert(CoinMagnet {
+                radius: power_up.strength,
+            }),
+            PowerUpKind::AirJump => player.insert(ExtraAirJumps(power_up.strength as u32)),
+        };
+    }
+
+    commands.entity(entity).insert(ActivePowerUps(effects));
+}
+
+/// Ticks active power-ups and removes their effect when they run out.
+pub fn tick_power_ups(
+    mut commands: Commands,
+    time: Res<Time>,
+    mut query: Query<(Entity, &mut ActivePowerUps)>,
+) {
+    for (entity, mut active) in &mut query {
+        active.0.retain_mut(|power_up| {
+            power_up.timer.tick(time.delta());
+            if power_up.timer.is_finished() {
+                remove_effect(&mut commands.entity(entity), power_up.kind);
+                false
+            } else {
+                true
+            }
+        });
+    }
+}
+
+/// Reverts all power-up effects when the player dies.
+pub fn clear_power_ups_on_death(
+    mut commands: Commands,
+    query: Query<(Entity, &ActivePowerUps), (With<Player>, Added<DeathTimer>)>,
+) {
+    for (entity, active) in &query {
+        let mut player = commands.entity(entity);
+        for power_up in &active.0 {
+            remove_effect(&mut player, power_up.kind);
+        }
+        player.remove::<ActivePowerUps>();
+    }
+}
+
+fn remove_effect(player: &mut EntityCommands, kind: PowerUpKind) {
+    match kind {
+        PowerUpKind::Speed => player.remove::<SpeedBoost>(),
+        PowerUpKind::Jump => player.remove::<JumpBoost>(),
+        PowerUpKind::Invulnerable => player.remove::<Invulnerable>(),
+        PowerUpKind::Magnet => player.remove::<CoinMagnet>(),
+        PowerUpKind::AirJump => player.remove::<ExtraAirJumps>(),
+    };
+}
+
+/// Pulls nearby coins toward a player with an active coin magnet.
+pub fn attract_coins(
+    time: Res<Time>,
+    player: Query<(&Transform, &CoinMagnet), With<Player>>,
+    mut coins: Query<(&mut Transform, &Pickup), (Without<Collected>, Without<Player>)>,
+) {
+    let Ok((player_transform, magnet)) = player.single() else {
+        return;
+    };
+    let target = player_transform.translation.truncate();
+
+    for (mut transform, pickup) in &mut coins {
+        if pickup.kind != PickupKind::Coin {
+            continue;
+        }
+
+        let pos = transform.translation.truncate();
+        let distance = pos.distance(target);
+        if distance <= magnet.radius {
+            // Faster the closer the coin gets
+            let step = (magnet.radius - distance + 60.0) * time.delta_secs();
+            let new_pos = pos.move_towards(target, step);
+            transform.translation.x = new_pos.x;
+            transform.translation.y = new_pos.y;
+        }
+    }
+}
+
+/// Blinks the player sprite while invulnerable.
+pub fn flash_invulnerable_player(
+    time: Res<Time>,
+    mut player: Query<(&mut Sprite, Has<Invulnerable>), With<Player>>,
+) {
+    for (mut sprite, invulnerable) in &mut player {
+        let alpha = if invulnerable && ((time.elapsed_secs() * 10.0) as u32).is_multiple_of(2) {
+            0.4
+        } else {
+            1.0
+        };
+        if sprite.color.alpha() != alpha {
+            sprite.color.set_alpha(alpha);
+        }
+    }
+}
+
+/// Spawns the HUD line listing active power-ups.
+pub fn spawn_power_up_hud(mut commands: Commands, asset_server: Res<AssetServer>) {
+    commands.spawn((
+        Name::new("Power-up HUD"),
+        PowerUpHud,
+        Text::default(),
+        TextFont {
+            font: asset_server.load("fonts/PixelOperator8.ttf"),
+            font_size: 16.0,
+            ..default()
+        },
+        TextColor(Color::WHITE),
+        Node {
+            position_type: PositionType::Absolute,
+            top: Val::Px(12.0),
+            right: Val::Px(12.0),
+            ..default()
+        },
+    ));
+}
+
+/// Shows each active power-up with its remaining time.
+pub fn update_power_up_hud(
+    player: Query<Option<&ActivePowerUps>, With<Player>>,
+    mut hud: Query<&mut Text, With<PowerUpHud>>,
+) {
+    let lines: Vec<String> = player
+        .single()
+        .ok()
+        .flatten()
+        .map(|active| {
+            active
+                .0
+                .iter()
+                .map(|p| format!("{:?} {:.1}s", p.kind, p.timer.remaining_secs()))
+                .collect()
+        })
+        .unwrap_or_default();
+    let content = lines.join("\n");
+
+    for mut text in &mut hud {
+        if text.0 != content {
+            text.0 = content.clone();
+        }
+    }
+}
@@ -0,0 +1,82 @@
+use crate::pickups::components::{Pickup, PickupKind};
+use bevy::{platform::collections::HashSet, prelude::*};
+
+/// Color shared by a key and the doors it opens.
+#[derive(Reflect, Default, Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum KeyColor {
+    #[default]
+    Red,
+    Blue,
+    Green,
+    Yellow,
+}
+
+/// Key pickup placed in Tiled.
+#[derive(Component, Reflect, Default, Debug, Clone)]
+#[reflect(Component, Default)]
+#[require(Pickup = Pickup { kind: PickupKind::Key, value: 0, respawn_time: 0.0 })]
+pub struct Key {
+    pub color: KeyColor,
+}
+
+/// Keys the player is carrying.
+#[derive(Component, Reflect, Default)]
+#[reflect(Component)]
+pub struct KeyRing(pub HashSet<KeyColor>);
+
+/// Solid Tiled collider that opens when touched by a player carrying the matching key.
+#[derive(Component, Reflect, Default, Debug, Clone)]
+#[reflect(Component, Default)]
+pub struct Door {
+    pub color: KeyColor,
+}
+
+/// How a switch reacts to the player.
+#[derive(Reflect, Default, Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SwitchMode {
+    /// On while the player stands on it.
+    #[default]
+    Pressure,
+    /// Toggled by pressing interact while overlapping it.
+    Lever,
+}
+
+/// Tiled sensor area that powers the linked target object.
+#[derive(Component, Reflect, Debug, Clone)]
+#[reflect(Component, Default)]
+pub struct Switch {
+    pub mode: SwitchMode,
+    /// Tiled object reference to the gate or platform this switch controls
+    pub target: Entity,
+    #[reflect(ignore)]
+    pub on: bool,
+}
+
+impl Default for Switch {
+    fn default() -> Self {
+        Self {
+            mode: SwitchMode::Pressure,
+            target: Entity::PLACEHOLDER,
+            on: false,
+        }
+    }
+}
+
+/// Marks that the player currently overlaps this switch.
+#[derive(Component, Default)]
+pub struct PlayerOnSwitch;
+
+/// Added to an object linked from a switch. Targets decide what being powered means.
+#[derive(Component, Reflect, Default, Debug)]
+#[reflect(Component)]
+pub struct SwitchTarget {
+    pub powered: bool,
+}
+
+/// Solid Tiled collider that opens while its linked switch is powered.
+#[derive(Component, Reflect, Default, Debug, Clone)]
+#[reflect(Component, Default)]
+pub struct Gate {
+    /// Start open and close when powered instead
+    pub inverted: bool,
+}
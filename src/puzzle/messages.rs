@@ -0,0 +1,8 @@
+use bevy::prelude::*;
+
+/// Sent when a switch turns on or off.
+#[derive(Message, Debug, Clone)]
+pub struct SwitchToggled {
+    pub target: Entity,
+    pub on: bool,
+}
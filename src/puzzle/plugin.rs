@@ -0,0 +1,32 @@
+use super::components::{Door, Gate, Key, KeyRing, Switch, SwitchTarget};
+use super::messages::SwitchToggled;
+use super::systems::{
+    collect_keys, link_switch_targets, open_doors, setup_puzzle_colliders, track_switch_overlap,
+    update_gates, update_switches,
+};
+use crate::pickups::PickupSystemSet;
+use bevy::prelude::*;
+
+pub struct PuzzlePlugin;
+
+impl Plugin for PuzzlePlugin {
+    fn build(&self, app: &mut App) {
+        app.register_type::<Key>()
+            .register_type::<KeyRing>()
+            .register_type::<Door>()
+            .register_type::<Switch>()
+            .register_type::<SwitchTarget>()
+            .register_type::<Gate>()
+            .add_message::<SwitchToggled>()
+            .add_systems(
+                Update,
+                (
+                    setup_puzzle_colliders,
+                    link_switch_targets,
+                    collect_keys.after(PickupSystemSet::Collect),
+                    open_doors,
+                    (track_switch_overlap, update_switches, update_gates).chain(),
+                ),
+            );
+    }
+}
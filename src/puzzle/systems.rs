@@ -0,0 +1,217 @@
+use super::components::{
+    Door, Gate, Key, KeyRing, PlayerOnSwitch, Switch, SwitchMode, SwitchTarget,
+};
+use super::messages::SwitchToggled;
+use crate::pickups::components::PickupKind;
+use crate::pickups::messages::PickupCollected;
+use crate::player::{Player, PlayerInput};
+use avian2d::prelude::*;
+use bevy::prelude::*;
+use bevy_ecs_tiled::prelude::*;
+
+/// Makes switch colliders into sensors and lets doors report collisions.
+pub fn setup_puzzle_colliders(
+    mut collider_events: MessageReader<TiledEvent<ColliderCreated>>,
+    switches: Query<(), With<Switch>>,
+    doors: Query<(), With<Door>>,
+    mut commands: Commands,
+) {
+    for evt in collider_events.read() {
+        let owner = *evt.event.collider_of;
+        if switches.contains(owner) {
+            commands
+                .entity(evt.origin)
+                .insert((Sensor, CollisionEventsEnabled));
+        } else if doors.contains(owner) {
+            commands.entity(evt.origin).insert(CollisionEventsEnabled);
+        }
+    }
+}
+
+/// Marks objects referenced by a switch as switch targets.
+pub fn link_switch_targets(mut commands: Commands, switches: Query<&Switch, Added<Switch>>) {
+    for switch in &switches {
+        if let Ok(mut target) = commands.get_entity(switch.target) {
+            target.insert(SwitchTarget::default());
+        } else {
+            warn!("Switch target {:?} does not exist", switch.target);
+        }
+    }
+}
+
+/// Adds collected keys to the player's key ring.
+pub fn collect_keys(
+    mut commands: Commands,
+    mut messages: MessageReader<PickupCollected>,
+    keys: Query<&Key>,
+    mut player: Query<(Entity, Option<&mut KeyRing>), With<Player>>,
+) {
+    for evt in messages.read() {
+        if evt.kind != PickupKind::Key {
+            continue;
+        }
+        let (Ok(key), Ok((entity, key_ring))) = (keys.get(evt.entity), player.single_mut()) else {
+            continue;
+        };
+
+        match key_ring {
+            Some(mut key_ring) => {
+                key_ring.0.insert(key.color);
+            }
+            None => {
+                let mut key_ring = KeyRing::default();
+                key_ring.0.insert(key.color);
+                commands.entity(entity).insert(key_ring);
+            }
+        }
+    }
+}
+
+/// Opens doors touched by a player carrying the matching key, including doors the player
+/// was already touching when the key was collected.
+pub fn open_doors(
+    mut collision_events: MessageReader<CollisionStart>,
+    mut commands: Commands,
+    collisions: Collisions,
+    doors: Query<&Door>,
+    players: Query<&KeyRing, With<Player>>,
+    new_keys: Query<Entity, (With<Player>, Changed<KeyRing>)>,
+    collider_query: Query<&TiledColliderOf>,
+) {
+    let door_of = |collider| {
+        collider_query
+            .get(collider)
+            .ok()
+            .map(|c| c.0)
+            .filter(|owner| doors.contains(*owner))
+    };
+
+    // (door, player body) pairs in contact
+    let mut touching = Vec::new();
+    for evt in collision_events.read() {
+        match (door_of(evt.collider1), door_of(evt.collider2)) {
+            (Some(door), None) => touching.extend(evt.body2.map(|body| (door, body))),
+            (None, Some(door)) => touching.extend(evt.body1.map(|body| (door, body))),
+            _ => {}
+        }
+    }
+    for player in &new_keys {
+        for pair in collisions.collisions_with(player) {
+            let other = if pair.collider1 == player {
+                pair.collider2
+            } else {
+                pair.collider1
+            };
+            if pair.is_touching()
+                && let Some(door) = door_of(other)
+            {
+                touching.push((door, player));
+            }
+        }
+    }
+    touching.sort();
+    touching.dedup();
+
+    for (door_entity, player) in touching {
+        let (Ok(door), Ok(key_ring)) = (doors.get(door_entity), players.get(player)) else {
+            continue;
+        };
+        if key_ring.0.contains(&door.color) {
+            info!("Opened {:?} door", door.color);
+            commands.entity(door_entity).despawn();
+        }
+    }
+}
+
+/// Tracks whether the player overlaps each switch.
+pub fn track_switch_overlap(
+    mut start_events: MessageReader<CollisionStart>,
+    mut end_events: MessageReader<CollisionEnd>,
+    mut commands: Commands,
+    switches: Query<(), With<Switch>>,
+    players: Query<(), With<Player>>,
+    collider_query: Query<&TiledColliderOf>,
+) {
+    let switch_and_player = |collider1, collider2, body1: Option<Entity>, body2: Option<Entity>| {
+        let switch_of = |collider| {
+            collider_query
+                .get(collider)
+                .ok()
+                .map(|c| c.0)
+                .filter(|owner| switches.contains(*owner))
+        };
+        match (switch_of(collider1), switch_of(collider2)) {
+            (Some(switch), None) => body2.filter(|b| players.contains(*b)).map(|_| switch),
+            (None, Some(switch)) => body1.filter(|b| players.contains(*b)).map(|_| switch),
+            _ => None,
+        }
+    };
+
+    for evt in start_events.read() {
+        if let Some(switch) = switch_and_player(evt.collider1, evt.collider2, evt.body1, evt.body2)
+        {
+            commands.entity(switch).insert(PlayerOnSwitch);
+        }
+    }
+
+    for evt in end_events.read() {
+        if let Some(switch) = switch_and_player(evt.collider1, evt.collider2, evt.body1, evt.body2)
+        {
+            commands.entity(switch).remove::<PlayerOnSwitch>();
+        }
+    }
+}
+
+/// Turns switches on and off and powers their targets.
+pub fn update_switches(
+    mut input: ResMut<PlayerInput>,
+    mut switches: Query<(&mut Switch, Has<PlayerOnSwitch>)>,
+    mut targets: Query<&mut SwitchTarget>,
+    mut toggled: MessageWriter<SwitchToggled>,
+) {
+    let interact = std::mem::take(&mut input.interact_requested);
+
+    for (mut switch, player_on) in &mut switches {
+        let on = match switch.mode {
+            SwitchMode::Pressure => player_on,
+            SwitchMode::Lever if player_on && interact => !switch.on,
+            SwitchMode::Lever => switch.on,
+        };
+        if on == switch.on {
+            continue;
+        }
+
+        switch.on = on;
+        if let Ok(mut target) = targets.get_mut(switch.target) {
+            target.powered = on;
+        }
+        toggled.write(SwitchToggled {
+            target: switch.target,
+            on,
+        });
+    }
+}
+
+/// Opens or closes gates when their switch power changes.
+pub fn update_gates(
+    mut commands: Commands,
+    gates: Query<(Entity, &Gate, &SwitchTarget), Changed<SwitchTarget>>,
+    colliders: Query<(Entity, &TiledColliderOf)>,
+) {
+    for (entity, gate, target) in &gates {
+        let open = target.powered != gate.inverted;
+
+        commands.entity(entity).insert(if open {
+            Visibility::Hidden
+        } else {
+            Visibility::Inherited
+        });
+        for (collider, _) in colliders.iter().filter(|(_, c)| c.0 == entity) {
+            if open {
+                commands.entity(collider).insert(ColliderDisabled);
+            } else {
+                commands.entity(collider).remove::<ColliderDisabled>();
+            }
+        }
+    }
+}
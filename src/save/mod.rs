@@ -0,0 +1,5 @@
+mod plugin;
+pub mod resources;
+mod systems;
+
+pub use plugin::SavePlugin;
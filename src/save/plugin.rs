@@ -0,0 +1,23 @@
+use super::resources::SaveData;
+use super::systems::{load_save_data, write_save_data};
+use crate::abilities::resources::UnlockedAbilities;
+use crate::pickups::resources::CollectedPickups;
+use crate::tips::resources::SeenTips;
+use bevy::prelude::*;
+
+pub struct SavePlugin;
+
+impl Plugin for SavePlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<SaveData>()
+            .add_systems(Startup, load_save_data)
+            .add_systems(
+                Last,
+                write_save_data.run_if(
+                    resource_changed::<CollectedPickups>
+                        .or(resource_changed::<SeenTips>)
+                        .or(resource_changed::<UnlockedAbilities>),
+                ),
+            );
+    }
+}
@@ -0,0 +1,16 @@
+use crate::abilities::components::Ability;
+use bevy::prelude::*;
+use serde::{Deserialize, Serialize};
+use std::collections::{BTreeMap, BTreeSet};
+
+/// Progress persisted to disk between sessions.
+#[derive(Resource, Serialize, Deserialize, Default, Debug, Clone, PartialEq)]
+#[serde(default)]
+pub struct SaveData {
+    /// Collected coin object ids, keyed by map asset path
+    pub collected_pickups: BTreeMap<String, BTreeSet<u32>>,
+    /// Tiled object ids of `once` tips that were already shown
+    pub seen_tips: BTreeSet<u32>,
+    /// Abilities unlocked so far
+    pub abilities: BTreeSet<Ability>,
+}
@@ -0,0 +1,69 @@
+use super::resources::SaveData;
+use crate::abilities::resources::UnlockedAbilities;
+use crate::pickups::resources::CollectedPickups;
+use crate::tips::resources::SeenTips;
+use bevy::prelude::*;
+
+const SAVE_PATH: &str = "save.ron";
+
+/// Loads the save file, if any, and restores progress from it.
+pub fn load_save_data(
+    mut commands: Commands,
+    mut collected: ResMut<CollectedPickups>,
+    mut seen_tips: ResMut<SeenTips>,
+    mut abilities: ResMut<UnlockedAbilities>,
+) {
+    let save = match std::fs::read_to_string(SAVE_PATH) {
+        Ok(contents) => ron::from_str::<SaveData>(&contents).unwrap_or_else(|err| {
+            warn!("Ignoring unreadable save file {SAVE_PATH}: {err}");
+            SaveData::default()
+        }),
+        Err(_) => SaveData::default(),
+    };
+
+    for (map, ids) in &save.collected_pickups {
+        collected
+            .maps
+            .entry(map.clone())
+            .or_default()
+            .extend(ids.iter().copied());
+    }
+    // Only coins from earlier sessions are drawn as ghosts
+    collected.previous_sessions = collected.maps.clone();
+    seen_tips.0.extend(save.seen_tips.iter().copied());
+    abilities.0.extend(save.abilities.iter().copied());
+
+    commands.insert_resource(save);
+}
+
+/// Copies progress into SaveData and writes it to disk when it changes.
+pub fn write_save_data(
+    collected: Res<CollectedPickups>,
+    seen_tips: Res<SeenTips>,
+    abilities: Res<UnlockedAbilities>,
+    mut save: ResMut<SaveData>,
+) {
+    let updated = SaveData {
+        collected_pickups: collected
+            .maps
+            .iter()
+            .map(|(map, ids)| (map.clone(), ids.iter().copied().collect()))
+            .collect(),
+        seen_tips: seen_tips.0.iter().copied().collect(),
+        abilities: abilities.0.iter().copied().collect(),
+    };
+
+    if *save == updated {
+        return;
+    }
+    *save = updated;
+
+    match ron::ser::to_string_pretty(&*save, ron::ser::PrettyConfig::default()) {
+        Ok(contents) => {
+            if let Err(err) = std::fs::write(SAVE_PATH, contents) {
+                warn!("Failed to write save file {SAVE_PATH}: {err}");
+            }
+        }
+        Err(err) => warn!("Failed to serialize save data: {err}"),
+    }
+}
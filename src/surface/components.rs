@@ -0,0 +1,31 @@
+use bevy::prelude::*;
+
+/// Launches the player upward when they land on it.
+/// Add to a Tiled object with a collider, or as a custom property on tileset tiles.
+#[derive(Component, Reflect, Debug, Clone)]
+#[reflect(Component, Default)]
+pub struct BouncePad {
+    /// Upward launch velocity, replacing the player's JumpVelocity
+    pub impulse: f32,
+}
+
+impl Default for BouncePad {
+    fn default() -> Self {
+        Self { impulse: 450.0 }
+    }
+}
+
+/// Surface that carries whoever stands on it sideways.
+/// Add to a Tiled object with a collider, or as a custom property on tileset tiles.
+#[derive(Component, Reflect, Debug, Clone)]
+#[reflect(Component, Default)]
+pub struct Conveyor {
+    /// Horizontal surface velocity in pixels per second (negative = left)
+    pub speed: f32,
+}
+
+impl Default for Conveyor {
+    fn default() -> Self {
+        Self { speed: 60.0 }
+    }
+}
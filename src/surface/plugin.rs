@@ -0,0 +1,19 @@
+use super::components::{BouncePad, Conveyor};
+use super::systems::apply_surfaces;
+use crate::player::PlayerSystemSet;
+use bevy::prelude::*;
+
+pub struct SurfacePlugin;
+
+impl Plugin for SurfacePlugin {
+    fn build(&self, app: &mut App) {
+        app.register_type::<BouncePad>()
+            .register_type::<Conveyor>()
+            .add_systems(
+                FixedUpdate,
+                apply_surfaces
+                    .after(PlayerSystemSet::Movement)
+                    .before(PlayerSystemSet::Animation),
+            );
+    }
+}
@@ -0,0 +1,87 @@
+use super::components::{BouncePad, Conveyor};
+use crate::player::{Grounded, Player, StandingOn, SurfaceVelocity};
+use avian2d::prelude::*;
+use bevy::prelude::*;
+use bevy_ecs_tiled::prelude::*;
+
+/// Distance below the player's center probed for a surface tile
+const FEET_PROBE: f32 = 8.0;
+
+/// Finds the entity defining the surface under the player: the Tiled object
+/// owning the ground collider, or else the tile entity below the player's feet.
+fn surface_entities(
+    standing_on: Entity,
+    feet: Vec2,
+    collider_query: &Query<&TiledColliderOf>,
+    tilemaps: &Query<(
+        &TileStorage,
+        &TilemapSize,
+        &TilemapTileSize,
+        &GlobalTransform,
+    )>,
+) -> Vec<Entity> {
+    let mut entities = vec![standing_on];
+    if let Ok(owner) = collider_query.get(standing_on) {
+        entities.push(owner.0);
+    }
+
+    // Tile layers are centered on their origin (TilemapAnchor::Center)
+    for (storage, size, tile_size, transform) in tilemaps {
+        let local = feet - transform.translation().truncate();
+        let x = (local.x / tile_size.x + size.x as f32 / 2.0).floor();
+        let y = (local.y / tile_size.y + size.y as f32 / 2.0).floor();
+        if x < 0.0 || y < 0.0 || x >= size.x as f32 || y >= size.y as f32 {
+            continue;
+        }
+        if let Some(tile) = storage.get(&TilePos::new(x as u32, y as u32)) {
+            entities.push(tile);
+        }
+    }
+
+    entities
+}
+
+/// Launches grounded players off bounce pads and carries them along conveyors.
+pub fn apply_surfaces(
+    mut commands: Commands,
+    mut players: Query<
+        (Entity, &Transform, &StandingOn, &mut LinearVelocity),
+        (With<Player>, With<Grounded>),
+    >,
+    airborne: Query<Entity, (With<Player>, With<SurfaceVelocity>, Without<Grounded>)>,
+    bounce_pads: Query<&BouncePad>,
+    conveyors: Query<&Conveyor>,
+    collider_query: Query<&TiledColliderOf>,
+    tilemaps: Query<(
+        &TileStorage,
+        &TilemapSize,
+        &TilemapTileSize,
+        &GlobalTransform,
+    )>,
+) {
+    for (entity, transform, standing_on, mut velocity) in &mut players {
+        let feet = transform.translation.truncate() - Vec2::Y * FEET_PROBE;
+        let surfaces = surface_entities(standing_on.0, feet, &collider_query, &tilemaps);
+
+        if let Some(pad) = surfaces.iter().find_map(|e| bounce_pads.get(*e).ok()) {
+            velocity.y = pad.impulse;
+            commands.entity(entity).remove::<Grounded>();
+        }
+
+        match surfaces.iter().find_map(|e| conveyors.get(*e).ok()) {
+            Some(conveyor) => {
+                commands
+                    .entity(entity)
+                    .insert(SurfaceVelocity(conveyor.speed));
+            }
+            None => {
+                commands.entity(entity).remove::<SurfaceVelocity>();
+            }
+        }
+    }
+
+    // Conveyor speed is not kept once the player leaves the ground
+    for entity in &airborne {
+        commands.entity(entity).remove::<SurfaceVelocity>();
+    }
+}
@@ -0,0 +1,22 @@
+use super::resources::{TimeScale, TimeScalePriority};
+use super::systems::{
+    apply_time_scale, clear_time_scale, hit_stop_on_enemy_defeat, tick_time_scale_requests,
+    toggle_pause,
+};
+use crate::state::GameState;
+use bevy::prelude::*;
+
+pub struct TimeScalePlugin;
+
+impl Plugin for TimeScalePlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<TimeScale>()
+            .register_type::<TimeScale>()
+            .register_type::<TimeScalePriority>()
+            .add_systems(OnEnter(GameState::Playing), clear_time_scale)
+            .add_systems(OnEnter(GameState::Reloading), clear_time_scale)
+            .add_systems(Update, (toggle_pause, hit_stop_on_enemy_defeat))
+            // Requests made anywhere this frame are combined once, for the next frame
+            .add_systems(Last, (tick_time_scale_requests, apply_time_scale).chain());
+    }
+}
@@ -0,0 +1,123 @@
+use bevy::prelude::*;
+
+/// Name of the player-requested pause
+pub const PAUSE: &str = "pause";
+
+/// How strongly a time-scale request claims the clock. Requests of the highest priority
+/// present override all others; requests sharing that priority multiply together.
+#[derive(Reflect, Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub enum TimeScalePriority {
+    /// Gameplay slow-motion, e.g. while dying
+    Gameplay,
+    /// Brief freezes on impacts
+    HitStop,
+    /// Player-requested pause
+    Pause,
+}
+
+/// A named request to run the game at `scale` times normal speed.
+#[derive(Reflect, Debug, Clone)]
+pub struct TimeScaleRequest {
+    pub name: &'static str,
+    pub scale: f32,
+    pub priority: TimeScalePriority,
+    /// Real seconds left. None lasts until removed.
+    pub remaining: Option<f32>,
+}
+
+/// Time-scale requests, combined once per frame into `Time<Virtual>`'s speed.
+/// Systems push and remove requests here rather than setting the speed themselves.
+/// All requests are dropped on game state transitions.
+#[derive(Resource, Reflect, Default, Debug)]
+#[reflect(Resource)]
+pub struct TimeScale {
+    pub requests: Vec<TimeScaleRequest>,
+}
+
+impl TimeScale {
+    /// Adds a request, replacing any with the same name.
+    pub fn push(
+        &mut self,
+        name: &'static str,
+        scale: f32,
+        priority: TimeScalePriority,
+        duration: Option<f32>,
+    ) {
+        self.remove(name);
+        self.requests.push(TimeScaleRequest {
+            name,
+            scale,
+            priority,
+            remaining: duration,
+        });
+    }
+
+    pub fn remove(&mut self, name: &'static str) {
+        self.requests.retain(|request| request.name != name);
+    }
+
+    pub fn contains(&self, name: &'static str) -> bool {
+        self.requests.iter().any(|request| request.name == name)
+    }
+
+    /// Whether the player has paused the game.
+    pub fn is_paused(&self) -> bool {
+        self.contains(PAUSE)
+    }
+
+    /// The combined scale: the product of the highest-priority requests, or 1 without any.
+    pub fn combined(&self) -> f32 {
+        let Some(top) = self.requests.iter().map(|r| r.priority).max() else {
+            return 1.0;
+        };
+        self.requests
+            .iter()
+            .filter(|r| r.priority == top)
+            .map(|r| r.scale)
+            .product()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn combined_defaults_to_normal_speed() {
+        assert_eq!(TimeScale::default().combined(), 1.0);
+    }
+
+    #[test]
+    fn combined_multiplies_requests_of_equal_priority() {
+        let mut time_scale = TimeScale::default();
+        time_scale.push("slow", 0.5, TimeScalePriority::Gameplay, None);
+        time_scale.push("slower", 0.25, TimeScalePriority::Gameplay, None);
+
+        assert_eq!(time_scale.combined(), 0.125);
+    }
+
+    #[test]
+    fn combined_uses_only_the_highest_priority() {
+        let mut time_scale = TimeScale::default();
+        time_scale.push("slow", 0.5, TimeScalePriority::Gameplay, None);
+        time_scale.push("hit", 0.1, TimeScalePriority::HitStop, Some(0.05));
+        assert_eq!(time_scale.combined(), 0.1);
+
+        time_scale.push(PAUSE, 0.0, TimeScalePriority::Pause, None);
+        assert_eq!(time_scale.combined(), 0.0);
+
+        time_scale.remove(PAUSE);
+        time_scale.remove("hit");
+        assert_eq!(time_scale.combined(), 0.5);
+    }
+
+    #[test]
+    fn push_replaces_a_request_with_the_same_name() {
+        let mut time_scale = TimeScale::default();
+        time_scale.push("slow", 0.5, TimeScalePriority::Gameplay, None);
+        time_scale.push("slow", 0.25, TimeScalePriority::Gameplay, None);
+
+        assert_eq!(time_scale.requests.len(), 1);
+        assert_eq!(time_scale.combined(), 0.25);
+    }
+}
@@ -0,0 +1,67 @@
+use super::resources::{PAUSE, TimeScale, TimeScalePriority};
+use crate::enemy::messages::EnemyDefeated;
+use bevy::prelude::*;
+
+/// Name, speed and length in real seconds of the freeze when an enemy is defeated
+const ENEMY_HIT_STOP: &str = "enemy_hit_stop";
+const ENEMY_HIT_STOP_SCALE: f32 = 0.05;
+const ENEMY_HIT_STOP_TIME: f32 = 0.08;
+
+/// Counts down timed requests on real time, so they expire even while slowed or paused.
+pub fn tick_time_scale_requests(time: Res<Time<Real>>, mut time_scale: ResMut<TimeScale>) {
+    let dt = time.delta_secs();
+    time_scale
+        .requests
+        .retain_mut(|request| match &mut request.remaining {
+            Some(remaining) => {
+                *remaining -= dt;
+                *remaining > 0.0
+            }
+            None => true,
+        });
+}
+
+/// Applies the combined scale to virtual time, pausing it at zero.
+pub fn apply_time_scale(time_scale: Res<TimeScale>, mut virtual_time: ResMut<Time<Virtual>>) {
+    let scale = time_scale.combined();
+    if scale <= 0.0 {
+        virtual_time.pause();
+        return;
+    }
+
+    virtual_time.unpause();
+    virtual_time.set_relative_speed(scale);
+}
+
+/// Drops every request so no slow-motion or pause outlives the state it was made in.
+pub fn clear_time_scale(mut time_scale: ResMut<TimeScale>) {
+    time_scale.requests.clear();
+}
+
+/// Toggles the pause request with Escape or P.
+pub fn toggle_pause(keyboard: Res<ButtonInput<KeyCode>>, mut time_scale: ResMut<TimeScale>) {
+    if !keyboard.any_just_pressed([KeyCode::Escape, KeyCode::KeyP]) {
+        return;
+    }
+
+    if time_scale.contains(PAUSE) {
+        time_scale.remove(PAUSE);
+    } else {
+        time_scale.push(PAUSE, 0.0, TimeScalePriority::Pause, None);
+    }
+}
+
+/// Briefly freezes the game when an enemy is defeated.
+pub fn hit_stop_on_enemy_defeat(
+    mut messages: MessageReader<EnemyDefeated>,
+    mut time_scale: ResMut<TimeScale>,
+) {
+    if messages.read().count() > 0 {
+        time_scale.push(
+            ENEMY_HIT_STOP,
+            ENEMY_HIT_STOP_SCALE,
+            TimeScalePriority::HitStop,
+            Some(ENEMY_HIT_STOP_TIME),
+        );
+    }
+}
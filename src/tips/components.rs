@@ -9,3 +9,65 @@ pub struct TipText;
 #[derive(Component, Reflect, Default)]
 #[reflect(Component)]
 pub struct ScoreText;
+
+/// Controls when a tip is shown. Read from the `TipTrigger` class property
+/// on a Tiled text object; tips without it are always visible.
+#[derive(Component, Reflect, Clone, Debug)]
+#[reflect(Component, Default)]
+pub struct TipTrigger {
+    /// Show while the player is within this distance (pixels) of the tip center. 0 = disabled.
+    pub radius: f32,
+    /// Tiled rectangle object the player must be inside to show the tip.
+    #[reflect(ignore)]
+    pub area: Option<Rect>,
+    /// Only show the tip the first time it is triggered.
+    pub once: bool,
+    /// Render as a screen-space dialog box with typewriter reveal.
+    pub dialog: bool,
+    /// Fade in/out duration in seconds
+    pub fade_time: f32,
+    /// Characters revealed per second in dialog mode
+    pub reveal_speed: f32,
+}
+
+impl Default for TipTrigger {
+    fn default() -> Self {
+        Self {
+            radius: 0.0,
+            area: None,
+            once: false,
+            dialog: false,
+            fade_time: 0.3,
+            reveal_speed: 30.0,
+        }
+    }
+}
+
+/// Runtime visibility of a triggered tip.
+#[derive(Component, Reflect, Default)]
+#[reflect(Component)]
+pub struct TipState {
+    /// Tiled object id, used to remember tips that were already shown.
+    pub id: u32,
+    /// World-space bounds of the tip text.
+    pub bounds: Rect,
+    /// Whether the trigger condition currently holds.
+    pub active: bool,
+    /// Current fade level, 0.0 = hidden, 1.0 = fully visible.
+    pub alpha: f32,
+    /// Text color as authored in Tiled.
+    pub color: Color,
+}
+
+/// Screen-space dialog box for tips with `dialog` enabled.
+#[derive(Component, Reflect, Default)]
+#[reflect(Component)]
+pub struct TipDialog;
+
+/// Reveals dialog text one character at a time.
+#[derive(Component, Reflect, Default)]
+#[reflect(Component)]
+pub struct Typewriter {
+    pub full_text: String,
+    pub revealed: f32,
+}
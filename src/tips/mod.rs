@@ -1,5 +1,6 @@
 mod components;
 mod plugin;
+pub mod resources;
 mod systems;
 
 pub use plugin::TipsPlugin;
@@ -1,5 +1,8 @@
-use super::components::{ScoreText, TipText};
-use super::systems::{spawn_tip_text, update_score_text};
+use super::components::{ScoreText, TipDialog, TipState, TipText, TipTrigger, Typewriter};
+use super::resources::SeenTips;
+use super::systems::{
+    fade_tips, spawn_tip_text, update_score_text, update_tip_dialogs, update_tip_triggers,
+};
 use crate::core::components::Score;
 use bevy::prelude::*;
 
@@ -9,7 +12,17 @@ impl Plugin for TipsPlugin {
     fn build(&self, app: &mut App) {
         app.register_type::<TipText>()
             .register_type::<ScoreText>()
+            .register_type::<TipTrigger>()
+            .register_type::<TipState>()
+            .register_type::<TipDialog>()
+            .register_type::<Typewriter>()
+            .register_type::<SeenTips>()
+            .init_resource::<SeenTips>()
             .add_systems(Update, spawn_tip_text)
+            .add_systems(
+                Update,
+                (update_tip_triggers, fade_tips, update_tip_dialogs).chain(),
+            )
             .add_systems(Update, update_score_text.run_if(resource_changed::<Score>));
     }
 }
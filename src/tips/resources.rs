@@ -0,0 +1,7 @@
+use bevy::{platform::collections::HashSet, prelude::*};
+
+/// Tiled object ids of `once` tips that were already shown.
+/// Not cleared on reload, so a tip stays dismissed after dying.
+#[derive(Resource, Reflect, Default)]
+#[reflect(Resource)]
+pub struct SeenTips(pub HashSet<u32>);
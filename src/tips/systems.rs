@@ -1,9 +1,12 @@
-use super::components::{ScoreText, TipText};
+use super::components::{ScoreText, TipDialog, TipState, TipText, TipTrigger, Typewriter};
+use super::resources::SeenTips;
 use crate::core::components::Score;
+use crate::player::Player;
+use crate::state::GameState;
 use bevy::prelude::*;
 use bevy::sprite::Anchor;
 use bevy_ecs_tiled::prelude::*;
-use tiled::ObjectShape;
+use tiled::{ObjectShape, PropertyValue};
 
 /// Spawns Text2d for text objects from Tiled.
 pub fn spawn_tip_text(
@@ -23,6 +26,10 @@ pub fn spawn_tip_text(
         let map_width = map.width as f32 * map.tile_width as f32;
         let map_height = map.height as f32 * map.tile_height as f32;
 
+        // Convert Tiled coords (top-left origin) to Bevy centered coords
+        // TilemapAnchor::Center puts map center at origin
+        let to_world = |x: f32, y: f32| Vec2::new(x - map_width / 2.0, map_height / 2.0 - y);
+
         for layer in map.layers() {
             let Some(object_layer) = layer.as_object_layer() else {
                 continue;
@@ -33,47 +40,233 @@ pub fn spawn_tip_text(
                     text,
                     pixel_size,
                     color,
+                    width,
+                    height,
                     ..
                 } = &object.shape
                 else {
                     continue;
                 };
 
-                // Convert Tiled coords (top-left origin) to Bevy centered coords
-                // TilemapAnchor::Center puts map center at origin
-                let x = object.x - map_width / 2.0;
-                let y = map_height / 2.0 - object.y;
+                let top_left = to_world(object.x, object.y);
+                let color = Color::srgba_u8(color.red, color.green, color.blue, color.alpha);
 
                 // Check if this is a ScoreText
                 let is_score_text = object.properties.contains_key("ScoreText");
 
-                let mut entity = commands.spawn((
-                    Name::new(format!("Tip: {}", text)),
-                    TipText,
-                    Text2d::new(text.clone()),
-                    TextFont {
-                        font: font.clone(),
-                        font_size: *pixel_size as f32,
-                        ..default()
-                    },
-                    TextColor(Color::srgba_u8(
-                        color.red,
-                        color.green,
-                        color.blue,
-                        color.alpha,
-                    )),
-                    Anchor::TOP_LEFT,
-                    Transform::from_xyz(x, y, 5.0),
-                ));
-
-                if is_score_text {
-                    entity.insert(ScoreText);
+                // Triggered tips start hidden and fade in when their condition holds
+                let trigger = match object.properties.get("TipTrigger") {
+                    Some(PropertyValue::ClassValue { properties, .. }) => {
+                        let mut trigger = tip_trigger_from_properties(properties);
+                        if let Some(PropertyValue::ObjectValue(area_id)) = properties.get("area") {
+                            trigger.area = map.layers().find_map(|layer| {
+                                layer.as_object_layer()?.objects().find_map(|area| {
+                                    let &ObjectShape::Rect { width, height } = &area.shape else {
+                                        return None;
+                                    };
+                                    (area.id() == *area_id).then(|| {
+                                        let min = to_world(area.x, area.y + height);
+                                        Rect::from_corners(min, min + Vec2::new(width, height))
+                                    })
+                                })
+                            });
+                        }
+                        Some(trigger)
+                    }
+                    _ => None,
+                };
+
+                let Some(trigger) = trigger else {
+                    let mut entity = commands.spawn((
+                        Name::new(format!("Tip: {}", text)),
+                        TipText,
+                        Text2d::new(text.clone()),
+                        TextFont {
+                            font: font.clone(),
+                            font_size: *pixel_size as f32,
+                            ..default()
+                        },
+                        TextColor(color),
+                        Anchor::TOP_LEFT,
+                        Transform::from_xyz(top_left.x, top_left.y, 5.0),
+                        DespawnOnExit(GameState::Playing),
+                    ));
+
+                    if is_score_text {
+                        entity.insert(ScoreText);
+                    }
+                    continue;
+                };
+
+                let state = TipState {
+                    id: object.id(),
+                    bounds: Rect::from_corners(top_left, top_left + Vec2::new(*width, -*height)),
+                    active: false,
+                    alpha: 0.0,
+                    color,
+                };
+
+                if trigger.dialog {
+                    // Screen-space box along the bottom edge, text revealed by Typewriter
+                    commands
+                        .spawn((
+                            Name::new(format!("Tip Dialog: {}", text)),
+                            TipDialog,
+                            trigger,
+                            state,
+                            Node {
+                                position_type: PositionType::Absolute,
+                                left: Val::Percent(10.0),
+                                right: Val::Percent(10.0),
+                                bottom: Val::Px(24.0),
+                                padding: UiRect::all(Val::Px(16.0)),
+                                ..default()
+                            },
+                            BackgroundColor(Color::NONE),
+                            DespawnOnExit(GameState::Playing),
+                        ))
+                        .with_child((
+                            Text::default(),
+                            TextFont {
+                                font: font.clone(),
+                                font_size: *pixel_size as f32 * 2.0,
+                                ..default()
+                            },
+                            TextColor(color.with_alpha(0.0)),
+                            Typewriter {
+                                full_text: text.clone(),
+                                revealed: 0.0,
+                            },
+                        ));
+                } else {
+                    commands.spawn((
+                        Name::new(format!("Tip: {}", text)),
+                        TipText,
+                        trigger,
+                        state,
+                        Text2d::new(text.clone()),
+                        TextFont {
+                            font: font.clone(),
+                            font_size: *pixel_size as f32,
+                            ..default()
+                        },
+                        TextColor(color.with_alpha(0.0)),
+                        Anchor::TOP_LEFT,
+                        Transform::from_xyz(top_left.x, top_left.y, 5.0),
+                        DespawnOnExit(GameState::Playing),
+                    ));
                 }
             }
         }
     }
 }
 
+/// Reads `TipTrigger` members, falling back to defaults for members Tiled left out.
+fn tip_trigger_from_properties(properties: &tiled::Properties) -> TipTrigger {
+    let mut trigger = TipTrigger::default();
+
+    for (name, value) in properties {
+        match (name.as_str(), value) {
+            ("radius", PropertyValue::FloatValue(v)) => trigger.radius = *v,
+            ("once", PropertyValue::BoolValue(v)) => trigger.once = *v,
+            ("dialog", PropertyValue::BoolValue(v)) => trigger.dialog = *v,
+            ("fade_time", PropertyValue::FloatValue(v)) => trigger.fade_time = *v,
+            ("reveal_speed", PropertyValue::FloatValue(v)) => trigger.reveal_speed = *v,
+            _ => {}
+        }
+    }
+
+    trigger
+}
+
+/// Activates tips whose radius or trigger area contains the player.
+pub fn update_tip_triggers(
+    player: Query<&Transform, With<Player>>,
+    mut tips: Query<(&TipTrigger, &mut TipState)>,
+    mut seen: ResMut<SeenTips>,
+) {
+    let player_pos = player.single().ok().map(|t| t.translation.truncate());
+
+    for (trigger, mut state) in &mut tips {
+        let in_range = player_pos.is_some_and(|pos| {
+            let near =
+                trigger.radius > 0.0 && pos.distance(state.bounds.center()) <= trigger.radius;
+            let inside = trigger.area.is_some_and(|area| area.contains(pos));
+            near || inside
+        });
+
+        // A `once` tip stays visible until the player leaves, then never again
+        let active = if trigger.once && !state.active && seen.0.contains(&state.id) {
+            false
+        } else {
+            in_range
+        };
+
+        if active && !state.active && trigger.once {
+            seen.0.insert(state.id);
+        }
+
+        if state.active != active {
+            state.active = active;
+        }
+    }
+}
+
+/// Fades triggered tips in and out.
+pub fn fade_tips(
+    time: Res<Time>,
+    mut tips: Query<(&TipTrigger, &mut TipState, Option<&mut TextColor>)>,
+) {
+    for (trigger, mut state, text_color) in &mut tips {
+        let target = if state.active { 1.0 } else { 0.0 };
+        let step = if trigger.fade_time > 0.0 {
+            time.delta_secs() / trigger.fade_time
+        } else {
+            1.0
+        };
+        state.alpha += (target - state.alpha).clamp(-step, step);
+
+        if let Some(mut text_color) = text_color {
+            text_color.0 = state.color.with_alpha(state.color.alpha() * state.alpha);
+        }
+    }
+}
+
+/// Fades the dialog box and reveals its text while the tip is active.
+pub fn update_tip_dialogs(
+    time: Res<Time>,
+    mut dialogs: Query<(&TipTrigger, &TipState, &mut BackgroundColor, &Children), With<TipDialog>>,
+    mut texts: Query<(&mut Text, &mut TextColor, &mut Typewriter)>,
+) {
+    for (trigger, state, mut background, children) in &mut dialogs {
+        background.0 = Color::srgba(0.0, 0.0, 0.0, 0.75 * state.alpha);
+
+        for child in children.iter() {
+            let Ok((mut text, mut color, mut typewriter)) = texts.get_mut(child) else {
+                continue;
+            };
+
+            color.0 = state.color.with_alpha(state.color.alpha() * state.alpha);
+
+            // Restart the reveal once the dialog has fully faded out
+            if !state.active && state.alpha <= 0.0 {
+                typewriter.revealed = 0.0;
+            } else if state.active {
+                typewriter.revealed += trigger.reveal_speed * time.delta_secs();
+            }
+
+            let shown: String = typewriter
+                .full_text
+                .chars()
+                .take(typewriter.revealed as usize)
+                .collect();
+            if text.0 != shown {
+                text.0 = shown;
+            }
+        }
+    }
+}
+
 /// Updates score text when score changes.
 pub fn update_score_text(score: Res<Score>, mut query: Query<&mut Text2d, With<ScoreText>>) {
     for mut text in &mut query {
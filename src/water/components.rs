@@ -0,0 +1,34 @@
+use bevy::prelude::*;
+
+/// Swimmable volume. Add to a Tiled rectangle object covering the water.
+#[derive(Component, Reflect, Debug, Clone)]
+#[reflect(Component, Default)]
+pub struct Water {
+    /// Gravity multiplier while swimming
+    pub gravity_scale: f32,
+    /// Velocity damping per second while swimming
+    pub drag: f32,
+    /// Maximum sinking speed in pixels per second
+    pub max_fall_speed: f32,
+    /// Upward velocity of a swim stroke (jump pressed in water)
+    pub stroke_velocity: f32,
+    /// Seconds the player can stay fully submerged before drowning. 0 = unlimited.
+    pub breath_time: f32,
+}
+
+impl Default for Water {
+    fn default() -> Self {
+        Self {
+            gravity_scale: 0.3,
+            drag: 3.0,
+            max_fall_speed: 60.0,
+            stroke_velocity: 150.0,
+            breath_time: 0.0,
+        }
+    }
+}
+
+/// Time left before a submerged player drowns.
+#[derive(Component, Reflect)]
+#[reflect(Component)]
+pub struct Breath(pub Timer);
@@ -0,0 +1,8 @@
+use bevy::prelude::*;
+
+/// Written when the player enters water.
+#[derive(Message)]
+pub struct Splash {
+    /// World-space point on the water surface
+    pub position: Vec2,
+}
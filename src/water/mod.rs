@@ -0,0 +1,6 @@
+pub mod components;
+pub mod messages;
+mod plugin;
+mod systems;
+
+pub use plugin::WaterPlugin;
@@ -0,0 +1,17 @@
+use super::components::{Breath, Water};
+use super::messages::Splash;
+use super::systems::{setup_water_sensors, track_water_overlap, update_breath};
+use crate::player::PlayerSystemSet;
+use bevy::prelude::*;
+
+pub struct WaterPlugin;
+
+impl Plugin for WaterPlugin {
+    fn build(&self, app: &mut App) {
+        app.register_type::<Water>()
+            .register_type::<Breath>()
+            .add_message::<Splash>()
+            .add_systems(Update, (setup_water_sensors, track_water_overlap))
+            .add_systems(FixedUpdate, update_breath.after(PlayerSystemSet::Movement));
+    }
+}
@@ -0,0 +1,148 @@
+use super::components::{Breath, Water};
+use super::messages::Splash;
+use crate::core::layers::GameLayer;
+use crate::killzone::components::DeathTimer;
+use crate::killzone::start_death;
+use crate::player::{PLAYER_HALF_HEIGHT, Player, Swimming};
+use avian2d::prelude::*;
+use bevy::prelude::*;
+use bevy_ecs_tiled::prelude::*;
+
+/// Makes water colliders into sensors when created by bevy_ecs_tiled.
+/// They leave the Wall layer so enemy wall probes don't treat water as a wall.
+pub fn setup_water_sensors(
+    mut collider_events: MessageReader<TiledEvent<ColliderCreated>>,
+    water: Query<(), With<Water>>,
+    mut commands: Commands,
+) {
+    for evt in collider_events.read() {
+        if water.contains(*evt.event.collider_of) {
+            commands.entity(evt.origin).insert((
+                Sensor,
+                CollisionEventsEnabled,
+                CollisionLayers::new(GameLayer::Default, LayerMask::ALL),
+            ));
+        }
+    }
+}
+
+/// Switches the player into swimming when entering water, splashing on the way in.
+pub fn track_water_overlap(
+    mut start_events: MessageReader<CollisionStart>,
+    mut end_events: MessageReader<CollisionEnd>,
+    mut commands: Commands,
+    mut splashes: MessageWriter<Splash>,
+    water: Query<&Water>,
+    players: Query<(&Position, Option<&Swimming>), With<Player>>,
+    colliders: Query<(&TiledColliderOf, &ColliderAabb)>,
+) {
+    // The water volume (owner, collider bounds) and player body in a collision, if any
+    let water_and_player = |collider1, collider2, body1: Option<Entity>, body2: Option<Entity>| {
+        let water_of = |collider| {
+            colliders
+                .get(collider)
+                .ok()
+                .filter(|(owner, _)| water.contains(owner.0))
+                .map(|(owner, aabb)| (owner.0, *aabb))
+        };
+        match (water_of(collider1), water_of(collider2)) {
+            (Some(water), None) => body2.filter(|b| players.contains(*b)).map(|b| (water, b)),
+            (None, Some(water)) => body1.filter(|b| players.contains(*b)).map(|b| (water, b)),
+            _ => None,
+        }
+    };
+
+    for evt in start_events.read() {
+        let Some(((owner, aabb), player)) =
+            water_and_player(evt.collider1, evt.collider2, evt.body1, evt.body2)
+        else {
+            continue;
+        };
+        let (Ok(settings), Ok((position, _))) = (water.get(owner), players.get(player)) else {
+            continue;
+        };
+
+        commands.entity(player).insert(Swimming {
+            water: owner,
+            surface: aabb.max.y,
+            gravity_scale: settings.gravity_scale,
+            drag: settings.drag,
+            max_fall_speed: settings.max_fall_speed,
+            stroke_velocity: settings.stroke_velocity,
+        });
+        splashes.write(Splash {
+            position: Vec2::new(position.x, aabb.max.y),
+        });
+    }
+
+    for evt in end_events.read() {
+        let Some(((owner, _), player)) =
+            water_and_player(evt.collider1, evt.collider2, evt.body1, evt.body2)
+        else {
+            continue;
+        };
+        // Only leave the water volume the player is swimming in
+        if players
+            .get(player)
+            .is_ok_and(|(_, swimming)| swimming.is_some_and(|s| s.water == owner))
+        {
+            commands.entity(player).remove::<Swimming>();
+        }
+    }
+}
+
+/// Runs down the breath of submerged players, drowning them when it runs out.
+/// The player is tinted blue as their breath runs low.
+pub fn update_breath(
+    mut commands: Commands,
+    time: Res<Time>,
+    mut players: Query<
+        (
+            Entity,
+            &Position,
+            &mut Sprite,
+            Option<&Swimming>,
+            Option<&mut Breath>,
+        ),
+        (With<Player>, Without<DeathTimer>),
+    >,
+    water: Query<&Water>,
+) {
+    for (player, position, mut sprite, swimming, breath) in &mut players {
+        let breath_time = swimming
+            .filter(|s| position.y + PLAYER_HALF_HEIGHT < s.surface)
+            .and_then(|s| water.get(s.water).ok())
+            .map(|w| w.breath_time)
+            .filter(|t| *t > 0.0);
+
+        let mut used = 0.0;
+        match (breath_time, breath) {
+            (Some(_), Some(mut breath)) => {
+                breath.0.tick(time.delta());
+                used = breath.0.fraction();
+                if breath.0.just_finished() {
+                    info!("Player drowned! Starting death timer...");
+                    start_death(&mut commands, player);
+                }
+            }
+            (Some(breath_time), None) => {
+                commands
+                    .entity(player)
+                    .insert(Breath(Timer::from_seconds(breath_time, TimerMode::Once)));
+            }
+            // Surfaced or out of the water: breath refills at once
+            (None, Some(_)) => {
+                commands.entity(player).remove::<Breath>();
+            }
+            (None, None) => {}
+        }
+
+        let alpha = sprite.color.alpha();
+        let tint = Color::WHITE
+            .mix(&Color::srgb(0.4, 0.5, 1.0), used)
+            .with_alpha(alpha);
+        if sprite.color != tint {
+            sprite.color = tint;
+        }
+    }
+}